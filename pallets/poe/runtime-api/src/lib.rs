@@ -0,0 +1,134 @@
+//! POE pallet 的运行时 API 定义, 供前端和 RPC 查询存证信息.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use sp_std::vec::Vec;
+
+/// 存证的当前状态
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum ClaimStatus {
+	/// 存证已被声明且仍然有效
+	Active,
+	/// 存证已被持有者撤销, 在被彻底删除之前无法再次声明
+	Revoked,
+	/// 存证从未被声明, 或者已被彻底删除
+	Unknown,
+}
+
+sp_api::decl_runtime_apis! {
+	#[api_version(24)]
+	pub trait PoeApi<AccountId, BlockNumber, HashAlgo>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		HashAlgo: Codec,
+	{
+		// 除 `get_proof_info_signed` 外, 所有查询都把调用者视为匿名账户, 不返回私有的存证.
+
+		/// 返回命名空间中存证的持有者和创建时的区块号, 存证不存在或是私有的时返回 `None`.
+		fn get_proof_info(namespace: Vec<u8>, proof: Vec<u8>) -> Option<(AccountId, BlockNumber)>;
+		/// 与 `get_proof_info` 相同, 但私有的存证对持有者和被授权的查看者也返回.
+		/// `signature` 是 `viewer` 对 SCALE 编码的 `(b"poe/view", namespace, proof)` 的签名,
+		/// 签名无效时等同于 `get_proof_info`.
+		///
+		/// 这只是查询接口层面的隐私: 链上存储仍然是公开的, 签名也可以被得到它的任何人重复使用.
+		fn get_proof_info_signed(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+			viewer: AccountId,
+			signature: Vec<u8>,
+		) -> Option<(AccountId, BlockNumber)>;
+		/// 返回 `account` 当前持有的所有公开的存证及其所在的命名空间.
+		fn proofs_of(account: AccountId) -> Vec<(Vec<u8>, Vec<u8>)>;
+		/// 返回命名空间中的所有公开的存证.
+		fn proofs_in_namespace(namespace: Vec<u8>) -> Vec<Vec<u8>>;
+		/// 验证存证是否已被声明, 返回其持有者和创建时的区块号, 存证不存在或是私有的时返回 `None`.
+		fn verify_claim(namespace: Vec<u8>, proof: Vec<u8>) -> Option<(AccountId, BlockNumber)>;
+		/// 返回命名空间中带有 `tag` 标签的所有公开的存证.
+		fn proofs_by_tag(namespace: Vec<u8>, tag: Vec<u8>) -> Vec<Vec<u8>>;
+		/// 按顺序返回命名空间中的每个存证是否已被声明, 用于一次验证多个存证. 私有的存证视为不存在.
+		/// 单次查询的存证数量受 pallet 内部的上限约束, 超出的部分被忽略.
+		fn proofs_exist(namespace: Vec<u8>, proofs: Vec<Vec<u8>>) -> Vec<bool>;
+		/// 返回命名空间中存证的当前状态, 私有的存证返回 `Unknown`.
+		fn claim_status(namespace: Vec<u8>, proof: Vec<u8>) -> ClaimStatus;
+		/// 返回当前存在的存证总数.
+		fn total_proofs() -> u32;
+		/// 返回在达到存证数量上限之前还可以创建的存证数量.
+		fn remaining_capacity() -> u32;
+		/// 从 `start_key` 之后开始分页返回所有存证, 每一项为命名空间、存证、持有者和创建时的区块号.
+		/// 同时返回获取下一页时使用的 `start_key`, 已经没有更多存证时为 `None`.
+		/// 单页返回的存证数量不超过 `limit`, 且同时受 pallet 内部的上限约束.
+		/// 私有的存证不会被返回, 但仍然计入单页的数量.
+		fn list_proofs(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>, AccountId, BlockNumber)>, Option<Vec<u8>>);
+		/// 返回生成存证时使用的哈希算法, 存证不存在或是私有的时返回 `None`.
+		fn hash_algo(namespace: Vec<u8>, proof: Vec<u8>) -> Option<HashAlgo>;
+		/// 返回编号对应的命名空间和存证, 没有该编号的存证或存证是私有的时返回 `None`.
+		fn proof_by_id(id: u64) -> Option<(Vec<u8>, Vec<u8>)>;
+		/// 返回存证的编号, 存证不存在或是私有的时返回 `None`.
+		fn claim_id(namespace: Vec<u8>, proof: Vec<u8>) -> Option<u64>;
+		/// 返回取代了存证的新版本存证, 存证没有被取代或任意一个版本是私有的时返回 `None`.
+		fn superseded_by(namespace: Vec<u8>, proof: Vec<u8>) -> Option<Vec<u8>>;
+		/// 从存证开始沿取代关系回溯到最初的版本, 依次返回被取代的各个旧版本, 最早的版本在最后.
+		/// 遇到私有的版本时停止回溯.
+		fn supersession_chain(namespace: Vec<u8>, proof: Vec<u8>) -> Vec<Vec<u8>>;
+		/// 返回存证内容的 MIME 类型, 存证不存在、是私有的或未设置内容类型时返回 `None`.
+		fn content_type(namespace: Vec<u8>, proof: Vec<u8>) -> Option<Vec<u8>>;
+		/// 返回存证被创建时的 Unix 时间戳(毫秒), 存证不存在、是私有的或未记录时间时返回 `None`.
+		fn proof_timestamp(namespace: Vec<u8>, proof: Vec<u8>) -> Option<u64>;
+		/// 返回存证的所有权转移记录, 每一项为转移后的持有者和转移时的区块号.
+		/// 私有的存证返回空列表.
+		fn transfer_history(namespace: Vec<u8>, proof: Vec<u8>) -> Vec<(AccountId, BlockNumber)>;
+		/// 返回创建区块在 `from` 和 `to` 之间(包含两端)的存证, 每一项为命名空间、存证、持有者和
+		/// 创建时的区块号. 返回的存证数量受 pallet 内部的上限约束, 私有的存证不会被返回.
+		/// 该查询会遍历全部存证, 应当只在归档节点上用于审计.
+		fn proofs_in_range(
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Vec<(Vec<u8>, Vec<u8>, AccountId, BlockNumber)>;
+		/// 返回存证在存储中的完整键, 供轻客户端通过 `state_getReadProof` 获取存储证明并验证存证.
+		/// 命名空间或存证超出长度限制时返回 `None`.
+		fn generate_claim_key(namespace: Vec<u8>, proof: Vec<u8>) -> Option<Vec<u8>>;
+		/// 检查 `who` 现在能否在命名空间下创建存证, 进行与 `create_claim` 相同的检查但不修改状态.
+		/// 检查失败时返回 pallet 的 `Error` 枚举中对应错误的编号.
+		fn can_create(who: AccountId, namespace: Vec<u8>, proof: Vec<u8>) -> Result<(), u8>;
+		/// 验证 `leaf` 包含在以已声明的存证 `root` 为根的 Merkle 树中. `path` 依次为从叶子到根的
+		/// 各层兄弟节点, `true` 表示兄弟节点在左侧, 使用声明 `root` 时记录的哈希算法 `H`.
+		/// 叶子节点为 `H(0x00 || leaf)`, 内部节点为 `H(0x01 || left || right)`.
+		/// `root` 是私有的存证时返回 `false`.
+		fn verify_inclusion(
+			namespace: Vec<u8>,
+			root: Vec<u8>,
+			leaf: Vec<u8>,
+			path: Vec<(Vec<u8>, bool)>,
+		) -> bool;
+		/// 返回账户当前持有的存证数量、创建过的存证总数和持有的存证被撤销的总数.
+		fn stats_of(account: AccountId) -> (u32, u32, u32);
+		/// 返回存证的所有属性, 每一项为属性名和属性值. 存证不存在、是私有的或没有设置属性时
+		/// 返回空列表.
+		fn attributes_of(namespace: Vec<u8>, proof: Vec<u8>) -> Vec<(Vec<u8>, Vec<u8>)>;
+		/// 按从新到旧的顺序返回最近创建且仍然存在的存证, 每一项为命名空间、存证、创建时的持有者
+		/// 和创建时的区块号. 已被撤销或过期的存证和私有的存证不会出现在结果中.
+		fn recent_proofs() -> Vec<(Vec<u8>, Vec<u8>, AccountId, BlockNumber)>;
+		/// 检查 `from` 现在能否将命名空间下的存证转移给 `to`, 进行与 `transfer_claim` 相同的检查
+		/// 但不修改状态. 检查失败时返回 pallet 的 `Error` 枚举中对应错误的编号.
+		fn can_transfer(
+			from: AccountId,
+			to: AccountId,
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Result<(), u8>;
+		/// 根据布隆过滤器判断存证是否可能存在. 返回 `false` 时存证一定不存在, 返回 `true` 时
+		/// 存证可能存在. 没有启用或尚未建立布隆过滤器时总是返回 `true`.
+		fn bloom_might_contain(namespace: Vec<u8>, proof: Vec<u8>) -> bool;
+		/// 返回账户下一个签名授权的操作(例如 `create_claim_signed`)需要在签名内容中包含的随机数.
+		fn claim_nonce(account: AccountId) -> u64;
+		/// 返回存证的撤销者和撤销时的区块号, 存证没有被撤销时返回 `None`. 创建存证因
+		/// `AlreadyRevoked` 失败后, 客户端可以以此向用户说明存证之前被谁撤销.
+		fn revocation_info(namespace: Vec<u8>, proof: Vec<u8>) -> Option<(AccountId, BlockNumber)>;
+	}
+}
@@ -0,0 +1,1127 @@
+//! Benchmarking setup for pallet-poe
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Poe;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	sp_io::crypto::{sr25519_generate, sr25519_sign},
+	sp_runtime::{
+		traits::{Bounded, IdentifyAccount, Zero},
+		KeyTypeId, MultiSignature, MultiSigner,
+	},
+	traits::{Currency, Get, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_std::{prelude::*, vec};
+
+/// 构造一个长度为 `MaxBytesInHash` 的存证, 前 4 个字节为 `index`, 以便生成互不相同的存证.
+fn proof<T: Config>(index: u32) -> BoundedVec<u8, T::MaxBytesInHash> {
+	let mut bytes = vec![0u8; T::MaxBytesInHash::get() as usize];
+	for (byte, value) in bytes.iter_mut().zip(index.to_le_bytes()) {
+		*byte = value;
+	}
+	bytes.try_into().expect("proof has exactly MaxBytesInHash bytes; qed")
+}
+
+/// 构造一个长度为 `MaxNamespaceLen` 的命名空间.
+fn namespace<T: Config>() -> NamespaceOf<T> {
+	vec![0xffu8; T::MaxNamespaceLen::get() as usize]
+		.try_into()
+		.expect("namespace has exactly MaxNamespaceLen bytes; qed")
+}
+
+/// 构造 `count` 个长度为 `MaxTagLen` 的互不相同的标签, 第一个字节为 `seed`.
+fn tags<T: Config>(count: u32, seed: u8) -> BoundedVec<TagOf<T>, T::MaxTagsPerProof> {
+	(0..count)
+		.map(|index| {
+			let mut tag = vec![seed; T::MaxTagLen::get() as usize];
+			for (byte, value) in tag.iter_mut().skip(1).zip(index.to_le_bytes()) {
+				*byte = value;
+			}
+			tag.try_into().expect("tag has exactly MaxTagLen bytes; qed")
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("count is at most MaxTagsPerProof; qed")
+}
+
+/// 生成存证作者签名时使用的密钥类型
+const AUTHOR_KEY_TYPE: KeyTypeId = KeyTypeId(*b"poe!");
+
+/// 为账户充值足够支付押金的余额, 并允许其创建存证.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+	AllowedCreators::<T>::insert(who, ());
+}
+
+/// 在 `namespace::<T>()` 中创建一个由 `owner` 持有的存证.
+fn create<T: Config>(owner: &T::AccountId, index: u32) -> BoundedVec<u8, T::MaxBytesInHash> {
+	let proof = proof::<T>(index);
+	Poe::<T>::create_claim(
+		RawOrigin::Signed(owner.clone()).into(),
+		namespace::<T>(),
+		proof.clone(),
+		HashAlgo::Blake2_256,
+	)
+	.expect("claim can be created");
+	proof
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::AuthoritySignature: From<MultiSignature>,
+			T::AuthoritySigner: From<MultiSigner>,
+	}
+
+	create_claim {
+		let l in T::MinBytesInHash::get() .. T::MaxBytesInHash::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let mut bytes = proof::<T>(0).into_inner();
+		bytes.truncate(l as usize);
+		let proof: BoundedVec<u8, T::MaxBytesInHash> =
+			bytes.try_into().expect("l is at most MaxBytesInHash; qed");
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), proof.clone(), HashAlgo::Blake2_256)
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(caller));
+	}
+
+	create_claim_with_priority {
+		let l in T::MinBytesInHash::get() .. T::MaxBytesInHash::get();
+
+		// 存证数量已达到上限, 唯一可以被移除的存证的优先级仅次于新的存证, 查找的开销最大
+		let victim_owner: T::AccountId = account("victim", 0, 0);
+		fund::<T>(&victim_owner);
+		let ns = namespace::<T>();
+		let victim = proof::<T>(1);
+		Poe::<T>::create_claim_with_priority(
+			RawOrigin::Signed(victim_owner).into(),
+			ns.clone(),
+			victim.clone(),
+			HashAlgo::Blake2_256,
+			u8::MAX - 1,
+		)?;
+		if T::ClaimEviction::get() {
+			ProofCount::<T>::put(T::MaxProofs::get());
+		}
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let mut bytes = proof::<T>(0).into_inner();
+		bytes.truncate(l as usize);
+		let proof: BoundedVec<u8, T::MaxBytesInHash> =
+			bytes.try_into().expect("l is at most MaxBytesInHash; qed");
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), proof.clone(), HashAlgo::Blake2_256, u8::MAX)
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(caller));
+		if T::ClaimEviction::get() {
+			assert!(!Proofs::<T>::contains_key(&ns, &victim));
+		}
+	}
+
+	create_soulbound_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = proof::<T>(0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(Soulbound::<T>::contains_key(&ns, &proof));
+	}
+
+	create_claim_signed {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = proof::<T>(0);
+		let public = sr25519_generate(AUTHOR_KEY_TYPE, None);
+		let author = T::AuthoritySigner::from(MultiSigner::from(public)).into_account();
+		let payload = Poe::<T>::signed_claim_payload(&ns, &proof, 0);
+		let signature = sr25519_sign(AUTHOR_KEY_TYPE, &public, &payload)
+			.expect("key was generated in the keystore; qed");
+		let signature = T::AuthoritySignature::from(MultiSignature::from(signature));
+	}: _(RawOrigin::Signed(caller.clone()), author.clone(), ns.clone(), proof.clone(), 0, signature)
+	verify {
+		let claim = Proofs::<T>::get(&ns, &proof).expect("claim was created");
+		assert_eq!(claim.owner, author);
+		assert_eq!(claim.creator, caller);
+		assert_eq!(AccountNonces::<T>::get(&author), 1);
+	}
+
+	create_claims {
+		let p in 1 .. T::MaxBatchSize::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proofs: BoundedVec<_, T::MaxBatchSize> = (0..p)
+			.map(proof::<T>)
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("p is at most MaxBatchSize; qed");
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), proofs)
+	verify {
+		assert_eq!(ClaimsOf::<T>::get(&caller).len() as u32, p);
+	}
+
+	create_shared_claim {
+		let o in 0 .. T::MaxOwners::get() - 1;
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = proof::<T>(0);
+		let co_owners: BoundedVec<T::AccountId, T::MaxOwners> = (0..o)
+			.map(|i| account("co_owner", i, 0))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("o is less than MaxOwners; qed");
+	}: _(RawOrigin::Signed(caller), ns.clone(), co_owners, proof.clone())
+	verify {
+		assert_eq!(SharedOwners::<T>::get(&ns, &proof).map(|owners| owners.len() as u32), Some(o + 1));
+	}
+
+	create_claim_no_deposit {
+		let l in T::MinBytesInHash::get() .. T::MaxBytesInHash::get();
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		let ns = namespace::<T>();
+		let mut bytes = proof::<T>(0).into_inner();
+		bytes.truncate(l as usize);
+		let proof: BoundedVec<u8, T::MaxBytesInHash> =
+			bytes.try_into().expect("l is at most MaxBytesInHash; qed");
+		let origin = T::DepositWaiverOrigin::successful_origin();
+	}: _<T::Origin>(origin, ns.clone(), proof.clone(), owner.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(owner));
+	}
+
+	propose_claim {
+		let a in 1 .. T::MaxApprovers::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = proof::<T>(0);
+		let approvers: BoundedVec<T::AccountId, T::MaxApprovers> = (0..a)
+			.map(|i| account("approver", i, 0))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("a is at most MaxApprovers; qed");
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), approvers, a)
+	verify {
+		assert!(PendingClaims::<T>::contains_key(&ns, &proof));
+		assert!(PendingClaimDeposits::<T>::contains_key(&ns, &proof));
+	}
+
+	approve_pending_claim {
+		// 最后一个批准使存证生效, 开销最大
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = proof::<T>(0);
+		let approver: T::AccountId = account("approver", 0, 0);
+		let approvers: BoundedVec<T::AccountId, T::MaxApprovers> =
+			vec![approver.clone()].try_into().expect("MaxApprovers is at least 1; qed");
+		Poe::<T>::propose_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			ns.clone(),
+			proof.clone(),
+			approvers,
+			1,
+		)?;
+	}: _(RawOrigin::Signed(approver), ns.clone(), proof.clone())
+	verify {
+		assert!(!PendingClaims::<T>::contains_key(&ns, &proof));
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(caller));
+	}
+
+	remove_pending_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = proof::<T>(0);
+		let approvers: BoundedVec<T::AccountId, T::MaxApprovers> =
+			vec![account("approver", 0, 0)].try_into().expect("MaxApprovers is at least 1; qed");
+		Poe::<T>::propose_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			ns.clone(),
+			proof.clone(),
+			approvers,
+			1,
+		)?;
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!PendingClaims::<T>::contains_key(&ns, &proof));
+		assert!(!PendingClaimDeposits::<T>::contains_key(&ns, &proof));
+	}
+
+	approve_revoke {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = proof::<T>(0);
+		let co_owner: T::AccountId = account("co_owner", 0, 0);
+		let co_owners: BoundedVec<T::AccountId, T::MaxOwners> =
+			vec![co_owner].try_into().expect("MaxOwners is at least 2; qed");
+		Poe::<T>::create_shared_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			ns.clone(),
+			co_owners,
+			proof.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), proof.clone())
+	verify {
+		assert!(RevokeApprovals::<T>::get(&ns, &proof).contains(&caller));
+	}
+
+	create_superseding_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let old_proof = create::<T>(&caller, 0);
+		let new_proof = proof::<T>(1);
+	}: _(RawOrigin::Signed(caller), ns.clone(), new_proof.clone(), old_proof.clone())
+	verify {
+		assert_eq!(SupersededBy::<T>::get(&ns, &old_proof), Some(new_proof));
+	}
+
+	transfer_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+	}: _(RawOrigin::Signed(caller), dest.clone(), ns.clone(), proof.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(dest));
+	}
+
+	transfer_and_set_metadata {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+		let metadata: BoundedVec<u8, T::MaxMetadataLength> =
+			vec![0u8; T::MaxMetadataLength::get() as usize]
+				.try_into()
+				.expect("exactly MaxMetadataLength; qed");
+	}: _(RawOrigin::Signed(caller), dest.clone(), ns.clone(), proof.clone(), metadata)
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(dest));
+		assert!(ProofMetadata::<T>::contains_key(&ns, &proof));
+	}
+
+	safe_transfer_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+	}: _(RawOrigin::Signed(caller), dest.clone(), ns.clone(), proof.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(dest));
+	}
+
+	transfer_to_subaccount {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let parent: T::AccountId = account("parent", 0, 0);
+		let dest = Poe::<T>::subaccount_of(&parent, 0);
+	}: _(RawOrigin::Signed(caller), parent, 0, ns.clone(), proof.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(dest));
+	}
+
+	transfer_claims {
+		let p in 1 .. T::MaxBatchSize::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proofs: BoundedVec<_, T::MaxBatchSize> = (0..p)
+			.map(|i| create::<T>(&caller, i))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("p is at most MaxBatchSize; qed");
+		let dest: T::AccountId = account("dest", 0, 0);
+	}: _(RawOrigin::Signed(caller), dest.clone(), ns, proofs)
+	verify {
+		assert_eq!(ClaimsOf::<T>::get(&dest).len() as u32, p);
+	}
+
+	transfer_claim_with_memo {
+		let m in 0 .. T::MaxMemoLen::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+		let memo: BoundedVec<u8, T::MaxMemoLen> =
+			vec![0u8; m as usize].try_into().expect("m is at most MaxMemoLen; qed");
+	}: _(RawOrigin::Signed(caller), dest.clone(), ns.clone(), proof.clone(), memo)
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(dest));
+	}
+
+	revoke_claim {
+		// 由撤销代理撤销, 以覆盖读取撤销代理的路径
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		Poe::<T>::add_revoke_delegate(RawOrigin::Signed(owner).into(), caller.clone())?;
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+		assert!(RevokedProofs::<T>::contains_key(&ns, &proof));
+		let deposit = TombstoneDeposits::<T>::get(&ns, &proof).map(|(_, deposit)| deposit);
+		assert_eq!(deposit.unwrap_or_default(), T::ClaimDeposit::get());
+	}
+
+	burn_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+		assert!(!RevokedProofs::<T>::contains_key(&ns, &proof));
+	}
+
+	force_revoke_claim {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+	}: _(RawOrigin::Root, ns.clone(), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+	}
+
+	council_revoke_claim {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+		let origin = T::CouncilOrigin::successful_origin();
+	}: _<T::Origin>(origin, ns.clone(), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+	}
+
+	challenge_claim {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let reason: BoundedVec<u8, T::MaxMetadataLength> =
+			vec![0u8; T::MaxMetadataLength::get() as usize]
+				.try_into()
+				.expect("exactly MaxMetadataLength; qed");
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), reason)
+	verify {
+		assert!(Challenges::<T>::contains_key(&ns, &proof));
+	}
+
+	resolve_challenge {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+		let challenger: T::AccountId = account("challenger", 0, 0);
+		fund::<T>(&challenger);
+		Poe::<T>::challenge_claim(
+			RawOrigin::Signed(challenger).into(),
+			ns.clone(),
+			proof.clone(),
+			Default::default(),
+		)?;
+	}: _(RawOrigin::Root, ns.clone(), proof.clone(), true)
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+		assert!(!Challenges::<T>::contains_key(&ns, &proof));
+	}
+
+	force_revoke_and_slash {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+	}: _(RawOrigin::Root, ns.clone(), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+	}
+
+	force_revoke_claims {
+		let p in 1 .. T::MaxBatchSize::get();
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proofs: BoundedVec<_, T::MaxBatchSize> = (0..p)
+			.map(|i| create::<T>(&owner, i))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("p is at most MaxBatchSize; qed");
+		let first = proofs[0].clone();
+	}: _(RawOrigin::Root, ns.clone(), proofs, true)
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &first));
+	}
+
+	list_for_sale {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let price: BalanceOf<T> = T::ClaimDeposit::get();
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), proof.clone(), price)
+	verify {
+		assert_eq!(Listings::<T>::get(&ns, &proof), Some((caller, price)));
+	}
+
+	buy_claim {
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T>(&seller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&seller, 0);
+		Poe::<T>::list_for_sale(
+			RawOrigin::Signed(seller).into(),
+			ns.clone(),
+			proof.clone(),
+			T::ClaimDeposit::get(),
+		)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), proof.clone(), T::ClaimDeposit::get())
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(caller));
+		assert!(!Listings::<T>::contains_key(&ns, &proof));
+	}
+
+	delist {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		Poe::<T>::list_for_sale(
+			RawOrigin::Signed(caller.clone()).into(),
+			ns.clone(),
+			proof.clone(),
+			T::ClaimDeposit::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!Listings::<T>::contains_key(&ns, &proof));
+	}
+
+	update_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let old_proof = create::<T>(&caller, 0);
+		let metadata: BoundedVec<u8, T::MaxMetadataLength> =
+			vec![0u8; T::MaxMetadataLength::get() as usize]
+				.try_into()
+				.expect("exactly MaxMetadataLength; qed");
+		Poe::<T>::set_metadata(
+			RawOrigin::Signed(caller.clone()).into(),
+			ns.clone(),
+			old_proof.clone(),
+			metadata,
+		)?;
+		let new_proof = proof::<T>(1);
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), old_proof.clone(), new_proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &old_proof));
+		assert_eq!(Proofs::<T>::get(&ns, &new_proof).map(|claim| claim.owner), Some(caller));
+	}
+
+	set_paused {
+	}: _(RawOrigin::Root, true)
+	verify {
+		assert!(Paused::<T>::get());
+	}
+
+	add_creator {
+		let who: T::AccountId = account("creator", 0, 0);
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(AllowedCreators::<T>::contains_key(&who));
+	}
+
+	remove_creator {
+		let who: T::AccountId = account("creator", 0, 0);
+		AllowedCreators::<T>::insert(&who, ());
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(!AllowedCreators::<T>::contains_key(&who));
+	}
+
+	freeze_account {
+		let who: T::AccountId = account("frozen", 0, 0);
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(FrozenAccounts::<T>::contains_key(&who));
+	}
+
+	unfreeze_account {
+		let who: T::AccountId = account("frozen", 0, 0);
+		FrozenAccounts::<T>::insert(&who, ());
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(!FrozenAccounts::<T>::contains_key(&who));
+	}
+
+	add_revoke_delegate {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert!(RevokeDelegates::<T>::contains_key(&caller, &delegate));
+	}
+
+	remove_revoke_delegate {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		RevokeDelegates::<T>::insert(&caller, &delegate, ());
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert!(!RevokeDelegates::<T>::contains_key(&caller, &delegate));
+	}
+
+	set_metadata {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let metadata: BoundedVec<u8, T::MaxMetadataLength> =
+			vec![0u8; T::MaxMetadataLength::get() as usize]
+				.try_into()
+				.expect("exactly MaxMetadataLength; qed");
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), metadata)
+	verify {
+		assert!(ProofMetadata::<T>::contains_key(&ns, &proof));
+	}
+
+	add_metadata_editor {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		// 编辑者列表中只剩最后一个空位时查找和插入的开销最大
+		let editors: Vec<T::AccountId> = (1..T::MaxMetadataEditors::get())
+			.map(|i| account("editor", i, 0))
+			.collect();
+		let editors: BoundedVec<_, _> =
+			editors.try_into().expect("at most MaxMetadataEditors; qed");
+		MetadataEditors::<T>::insert(&ns, &proof, editors);
+		let editor: T::AccountId = account("editor", 0, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), editor.clone())
+	verify {
+		assert!(MetadataEditors::<T>::get(&ns, &proof).contains(&editor));
+	}
+
+	remove_metadata_editor {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let editors: Vec<T::AccountId> = (0..T::MaxMetadataEditors::get())
+			.map(|i| account("editor", i, 0))
+			.collect();
+		let editor = editors.last().cloned().expect("MaxMetadataEditors is at least 1; qed");
+		let editors: BoundedVec<_, _> =
+			editors.try_into().expect("at most MaxMetadataEditors; qed");
+		MetadataEditors::<T>::insert(&ns, &proof, editors);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), editor.clone())
+	verify {
+		assert!(!MetadataEditors::<T>::get(&ns, &proof).contains(&editor));
+	}
+
+	allow_recipient {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		// 名单中只剩最后一个空位时查找和插入的开销最大
+		let allowlist: Vec<T::AccountId> = (1..T::MaxAllowedRecipients::get())
+			.map(|i| account("recipient", i, 0))
+			.collect();
+		let allowlist: BoundedVec<_, _> =
+			allowlist.try_into().expect("at most MaxAllowedRecipients; qed");
+		TransferAllowlist::<T>::insert(&ns, &proof, allowlist);
+		let recipient: T::AccountId = account("recipient", 0, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), recipient.clone())
+	verify {
+		assert!(TransferAllowlist::<T>::get(&ns, &proof).contains(&recipient));
+	}
+
+	disallow_recipient {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let allowlist: Vec<T::AccountId> = (0..T::MaxAllowedRecipients::get())
+			.map(|i| account("recipient", i, 0))
+			.collect();
+		let recipient = allowlist.last().cloned().expect("MaxAllowedRecipients is at least 1; qed");
+		let allowlist: BoundedVec<_, _> =
+			allowlist.try_into().expect("at most MaxAllowedRecipients; qed");
+		TransferAllowlist::<T>::insert(&ns, &proof, allowlist);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), recipient.clone())
+	verify {
+		assert!(!TransferAllowlist::<T>::get(&ns, &proof).contains(&recipient));
+	}
+
+	set_content_type {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let content_type: BoundedVec<u8, T::MaxMimeLen> =
+			vec![b'a'; T::MaxMimeLen::get() as usize].try_into().expect("exactly MaxMimeLen; qed");
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), content_type)
+	verify {
+		assert!(ContentTypes::<T>::contains_key(&ns, &proof));
+	}
+
+	set_attribute {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let key: AttributeKeyOf<T> =
+			vec![0u8; T::MaxKeyLen::get() as usize].try_into().expect("exactly MaxKeyLen; qed");
+		let value: AttributeValueOf<T> =
+			vec![0u8; T::MaxValueLen::get() as usize].try_into().expect("exactly MaxValueLen; qed");
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), key.clone(), value)
+	verify {
+		assert!(Attributes::<T>::contains_key((ns, proof), key));
+	}
+
+	clear_attribute {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let key: AttributeKeyOf<T> =
+			vec![0u8; T::MaxKeyLen::get() as usize].try_into().expect("exactly MaxKeyLen; qed");
+		Poe::<T>::set_attribute(
+			RawOrigin::Signed(caller.clone()).into(),
+			ns.clone(),
+			proof.clone(),
+			key.clone(),
+			Default::default(),
+		)?;
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), key.clone())
+	verify {
+		assert!(!Attributes::<T>::contains_key((ns, proof), key));
+	}
+
+	set_tags {
+		let t in 0 .. T::MaxTagsPerProof::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		// 先设置 `t` 个旧标签, 以覆盖移除旧标签索引的路径
+		Poe::<T>::set_tags(
+			RawOrigin::Signed(caller.clone()).into(),
+			ns.clone(),
+			proof.clone(),
+			tags::<T>(t, 0),
+		)?;
+		let new_tags = tags::<T>(t, 1);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), new_tags.clone())
+	verify {
+		assert_eq!(ProofTags::<T>::get(&ns, &proof).len() as u32, t);
+	}
+
+	renew_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		// 在新的区块中延长有效期, 以覆盖移出旧队列并加入新队列的路径
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(ProofExpiry::<T>::contains_key(&ns, &proof));
+	}
+
+	renew_claims {
+		let p in 1 .. T::MaxBatchSize::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proofs: BoundedVec<_, T::MaxBatchSize> = (0..p)
+			.map(|i| create::<T>(&caller, i))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("p is at most MaxBatchSize; qed");
+		let first = proofs[0].clone();
+		let expires_at = ProofExpiry::<T>::get(&ns, &first);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proofs, 1u32.into())
+	verify {
+		assert!(ProofExpiry::<T>::get(&ns, &first) > expires_at);
+	}
+
+	clean_expired {
+		let n in 0 .. T::MaxExpiredPerBlock::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		// 将所有存证放入同一个已经过期的区块的过期队列
+		let expires_at: T::BlockNumber = 1u32.into();
+		let mut expired = Vec::new();
+		for i in 0..n {
+			let proof = create::<T>(&caller, i);
+			if let Some(old) = ProofExpiry::<T>::take(&ns, &proof) {
+				ExpiryQueue::<T>::remove(old);
+			}
+			ProofExpiry::<T>::insert(&ns, &proof, expires_at);
+			expired.push((ns.clone(), proof));
+		}
+		let expired: BoundedVec<_, _> =
+			expired.try_into().expect("n is at most MaxExpiredPerBlock; qed");
+		ExpiryQueue::<T>::insert(expires_at, expired);
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+	}: _(RawOrigin::Signed(caller), n)
+	verify {
+		assert!(!ExpiryQueue::<T>::contains_key(expires_at));
+	}
+
+	// `e` 个存证在当前区块过期, `w` 个存证需要提醒, 之前的区块留下了 `c` 条创建记录.
+	// 只有启用 `AutoExpire` 时才会清理过期的存证, 生成权重时应当使用启用了它的运行时
+	on_initialize {
+		let e in 0 .. T::MaxExpiredPerBlock::get();
+		let w in 0 .. T::MaxExpiryWarningsPerBlock::get().min(T::MaxExpiredPerBlock::get());
+		let c in 0 .. MAX_CREATION_RECORDS_CLEARED;
+
+		let ns = namespace::<T>();
+		let now: T::BlockNumber = 2u32.into();
+		let window = T::ExpiryWarningBlocks::get();
+		let w = if window.is_zero() { 0 } else { w };
+		// 两组存证由不同的账户持有, 以免超出每个账户在一个区块内的创建数量限制
+		let queue = |owner: &T::AccountId, from: u32, count: u32, expires_at: T::BlockNumber| {
+			fund::<T>(owner);
+			let mut proofs = Vec::new();
+			for i in from..from + count {
+				let proof = create::<T>(owner, i);
+				if let Some(old) = ProofExpiry::<T>::take(&ns, &proof) {
+					ExpiryQueue::<T>::remove(old);
+				}
+				ProofExpiry::<T>::insert(&ns, &proof, expires_at);
+				proofs.push((ns.clone(), proof));
+			}
+			let proofs: BoundedVec<_, _> =
+				proofs.try_into().expect("count is at most MaxExpiredPerBlock; qed");
+			ExpiryQueue::<T>::insert(expires_at, proofs);
+		};
+		queue(&whitelisted_caller(), 0, e, now);
+		queue(&account("owner", 0, 0), e, w, now.saturating_add(window));
+		// 创建上面的存证时留下的记录不计入 `c`
+		let _ = CreationsThisBlock::<T>::remove_all(None);
+		for i in 0..c {
+			let creator: T::AccountId = account("creator", i, 0);
+			CreationsThisBlock::<T>::insert(creator, (T::BlockNumber::from(1u32), 1u32));
+		}
+		frame_system::Pallet::<T>::set_block_number(now);
+	}: {
+		Poe::<T>::on_initialize(now);
+	}
+	verify {
+		assert_eq!(CreationsThisBlock::<T>::iter().count(), 0);
+		if T::AutoExpire::get() {
+			assert!(!ExpiryQueue::<T>::contains_key(now));
+		}
+	}
+
+	handle_expiry {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		if let Some(old) = ProofExpiry::<T>::take(&ns, &proof) {
+			ExpiryQueue::<T>::remove(old);
+		}
+		ProofExpiry::<T>::insert(&ns, &proof, T::BlockNumber::from(1u32));
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+	}: _(RawOrigin::Root, ns.clone(), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+	}
+
+	query_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let response = Poe::<T>::verify_claim(&ns, &proof).encode();
+		let origin = T::QueryOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0, ns, proof)
+	verify {
+		let event: <T as frame_system::Config>::Event =
+			Event::<T>::ClaimQueryAnswered(0, response).into();
+		let events = frame_system::Pallet::<T>::events();
+		assert_eq!(events.last().map(|record| &record.event), Some(&event));
+	}
+
+	pin_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(Pinned::<T>::contains_key(&ns, &proof));
+	}
+
+	// 最坏情况: 存证在固定期间已经过期, 需要重新加入过期队列
+	unpin_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		if let Some(old) = ProofExpiry::<T>::take(&ns, &proof) {
+			ExpiryQueue::<T>::remove(old);
+		}
+		ProofExpiry::<T>::insert(&ns, &proof, T::BlockNumber::from(1u32));
+		Pinned::<T>::insert(&ns, &proof, ());
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!Pinned::<T>::contains_key(&ns, &proof));
+		assert_eq!(ProofExpiry::<T>::get(&ns, &proof), Some(3u32.into()));
+	}
+
+	approve_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let spender: T::AccountId = account("spender", 0, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), spender.clone())
+	verify {
+		assert_eq!(Approvals::<T>::get(&ns, &proof), Some(spender));
+	}
+
+	transfer_from {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		Poe::<T>::approve_transfer(
+			RawOrigin::Signed(owner).into(),
+			ns.clone(),
+			proof.clone(),
+			caller.clone(),
+		)?;
+		let dest: T::AccountId = account("dest", 0, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), dest.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(dest));
+		assert!(!Approvals::<T>::contains_key(&ns, &proof));
+	}
+
+	offer_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+	}: _(RawOrigin::Signed(caller), dest.clone(), ns.clone(), proof.clone())
+	verify {
+		assert_eq!(PendingTransfers::<T>::get(&ns, &proof), Some(dest));
+	}
+
+	accept_transfer {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&owner, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		Poe::<T>::offer_transfer(
+			RawOrigin::Signed(owner).into(),
+			caller.clone(),
+			ns.clone(),
+			proof.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), ns.clone(), proof.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&ns, &proof).map(|claim| claim.owner), Some(caller));
+		assert!(!PendingTransfers::<T>::contains_key(&ns, &proof));
+	}
+
+	cancel_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let dest: T::AccountId = account("dest", 0, 0);
+		Poe::<T>::offer_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			dest,
+			ns.clone(),
+			proof.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!PendingTransfers::<T>::contains_key(&ns, &proof));
+	}
+
+	lock_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(LockedProofs::<T>::contains_key(&ns, &proof));
+	}
+
+	unlock_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		Poe::<T>::lock_claim(RawOrigin::Signed(caller.clone()).into(), ns.clone(), proof.clone())?;
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!LockedProofs::<T>::contains_key(&ns, &proof));
+	}
+
+	lock_my_transfers {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(AccountTransferLock::<T>::contains_key(&caller));
+	}
+
+	unlock_my_transfers {
+		let caller: T::AccountId = whitelisted_caller();
+		AccountTransferLock::<T>::insert(&caller, ());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!AccountTransferLock::<T>::contains_key(&caller));
+	}
+
+	create_namespace {
+		let admin: T::AccountId = account("admin", 0, 0);
+		let ns = namespace::<T>();
+	}: _(RawOrigin::Root, ns.clone(), admin.clone(), 100)
+	verify {
+		assert_eq!(NamespaceConfig::<T>::get(&ns).map(|info| info.admin), Some(admin));
+	}
+
+	namespace_force_revoke {
+		let caller: T::AccountId = whitelisted_caller();
+		let ns = namespace::<T>();
+		Poe::<T>::create_namespace(RawOrigin::Root.into(), ns.clone(), caller.clone(), 100)?;
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund::<T>(&owner);
+		let proof = create::<T>(&owner, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&ns, &proof));
+		assert_eq!(NamespaceConfig::<T>::get(&ns).map(|info| info.count), Some(0));
+	}
+
+	set_namespace_max_proofs {
+		let admin: T::AccountId = account("admin", 0, 0);
+		let ns = namespace::<T>();
+		Poe::<T>::create_namespace(RawOrigin::Root.into(), ns.clone(), admin, 100)?;
+	}: _(RawOrigin::Root, ns.clone(), 200)
+	verify {
+		assert_eq!(NamespaceConfig::<T>::get(&ns).map(|info| info.max_proofs), Some(200));
+	}
+
+	// 最坏情况: 从中间继续重建, 并且还有剩余的存证没有加入
+	rebuild_bloom {
+		let n in 0 .. MAX_BLOOM_REBUILD_BATCH;
+
+		// 过滤器只需要存证的键, 直接写入存证以绕过创建数量的限制
+		let owner: T::AccountId = account("owner", 0, 0);
+		let ns = namespace::<T>();
+		for i in 0..=n {
+			let claim = ClaimInfo {
+				owner: owner.clone(),
+				creator: owner.clone(),
+				block_number: 1u32.into(),
+				deposit: Default::default(),
+			};
+			Proofs::<T>::insert(&ns, proof::<T>(i), claim);
+		}
+		let first = Proofs::<T>::iter_keys().next();
+		BloomRebuild::<T>::put((Poe::<T>::empty_bloom(), first));
+	}: _(RawOrigin::Root, n)
+	verify {
+		assert!(n == 0 || BloomRebuild::<T>::get().is_some());
+	}
+
+	set_private {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), true)
+	verify {
+		assert!(PrivateProofs::<T>::contains_key(&ns, &proof));
+	}
+
+	grant_view {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		// 查看者列表中只剩最后一个空位时查找和插入的开销最大
+		let viewers: Vec<T::AccountId> = (1..T::MaxViewers::get())
+			.map(|i| account("viewer", i, 0))
+			.collect();
+		let viewers: BoundedVec<_, _> = viewers.try_into().expect("at most MaxViewers; qed");
+		ViewPermissions::<T>::insert(&ns, &proof, viewers);
+		let viewer: T::AccountId = account("viewer", 0, 0);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), viewer.clone())
+	verify {
+		assert!(ViewPermissions::<T>::get(&ns, &proof).contains(&viewer));
+	}
+
+	revoke_view {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let ns = namespace::<T>();
+		let proof = create::<T>(&caller, 0);
+		let viewers: Vec<T::AccountId> = (0..T::MaxViewers::get())
+			.map(|i| account("viewer", i, 0))
+			.collect();
+		let viewer = viewers.last().cloned().expect("MaxViewers is at least 1; qed");
+		let viewers: BoundedVec<_, _> = viewers.try_into().expect("at most MaxViewers; qed");
+		ViewPermissions::<T>::insert(&ns, &proof, viewers);
+	}: _(RawOrigin::Signed(caller), ns.clone(), proof.clone(), viewer.clone())
+	verify {
+		assert!(!ViewPermissions::<T>::get(&ns, &proof).contains(&viewer));
+	}
+
+	impl_benchmark_test_suite!(Poe, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,117 @@
+//! 在交易进入交易池之前检查存证长度的 `SignedExtension`.
+//!
+//! 存证长度不合法的交易在执行时才会失败, 但在此之前已经占用了交易池和区块的资源.
+//! 将 `CheckProofSize` 加入运行时的 `SignedExtra` 后, 这类交易在验证阶段就会被拒绝.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{DispatchInfoOf, SignedExtension},
+		transaction_validity::{
+			InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+		},
+	},
+	traits::IsSubType,
+};
+use scale_info::TypeInfo;
+use sp_std::{fmt, marker::PhantomData};
+
+/// 存证长度不在 `MinBytesInHash` 和 `MaxBytesInHash` 之间时 `InvalidTransaction::Custom` 的错误码
+pub const INVALID_PROOF_LENGTH: u8 = 1;
+
+/// 拒绝存证长度不合法的创建存证的交易, 包括 `create_claim` 的各个变体、`propose_claim`、
+/// `create_claims` 以及以新存证替换旧存证的 `create_superseding_claim` 和 `update_claim`.
+///
+/// 与执行时一样, 检查的是经过 `ProofNormalizer` 转换后的规范形式的长度.
+/// `create_claim_unbounded` 和 `create_claims_unbounded` 不做检查: 前者在执行时以
+/// `ProofTooLong` 错误报告超长的存证, 后者会跳过无法创建的存证而不是失败.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckProofSize<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckProofSize<T> {
+	/// 创建一个 `CheckProofSize`.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// 存证的规范形式的长度是否在 `MinBytesInHash` 和 `MaxBytesInHash` 之间.
+	fn is_valid_proof(proof: &BoundedVec<u8, T::MaxBytesInHash>) -> bool {
+		let len = T::ProofNormalizer::normalize(proof.clone()).len();
+		len >= T::MinBytesInHash::get() as usize && len <= T::MaxBytesInHash::get() as usize
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckProofSize<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for CheckProofSize<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckProofSize")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckProofSize<T>
+where
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckProofSize";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let valid = match call.is_sub_type() {
+			Some(Call::create_claim { proof, .. }) |
+			Some(Call::create_claim_with_content_type { proof, .. }) |
+			Some(Call::create_claim_with_priority { proof, .. }) |
+			Some(Call::create_soulbound_claim { proof, .. }) |
+			Some(Call::create_shared_claim { proof, .. }) |
+			Some(Call::create_claim_signed { proof, .. }) |
+			Some(Call::create_claim_no_deposit { proof, .. }) |
+			Some(Call::propose_claim { proof, .. }) => Self::is_valid_proof(proof),
+			Some(Call::create_superseding_claim { new_proof, .. }) |
+			Some(Call::update_claim { new_proof, .. }) => Self::is_valid_proof(new_proof),
+			Some(Call::create_claims { proofs, .. }) => proofs.iter().all(Self::is_valid_proof),
+			_ => true,
+		};
+
+		if valid {
+			Ok(ValidTransaction::default())
+		} else {
+			InvalidTransaction::Custom(INVALID_PROOF_LENGTH).into()
+		}
+	}
+}
@@ -2,23 +2,442 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod proptests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod extensions;
+pub mod migrations;
+pub mod offchain;
+pub mod traits;
+pub mod weights;
+pub use traits::{
+	ExpiryScheduler, IdentityProvider, OnClaimEvent, ProofNormalizer, QueryResponder,
+};
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		dispatch::{DispatchErrorWithPostInfo, WithPostDispatchInfo},
+		pallet_prelude::*,
+		sp_io::KillStorageResult,
+		sp_runtime::{
+			traits::{IdentifyAccount, One, Saturating, TrailingZeroInput, Verify, Zero},
+			Permill,
+		},
+		traits::{
+			Currency, ExistenceRequirement, Imbalance, OnUnbalanced, ReservableCurrency, UnixTime,
+			WithdrawReasons,
+		},
+		transactional,
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	use crate::{
+		traits::{ExpiryScheduler, OnClaimEvent, ProofNormalizer, QueryResponder},
+		weights::WeightInfo,
+	};
+
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub(crate) type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	pub(crate) type NamespaceOf<T> = BoundedVec<u8, <T as Config>::MaxNamespaceLen>;
+
+	pub(crate) type TagOf<T> = BoundedVec<u8, <T as Config>::MaxTagLen>;
+
+	pub(crate) type AttributeKeyOf<T> = BoundedVec<u8, <T as Config>::MaxKeyLen>;
+
+	pub(crate) type AttributeValueOf<T> = BoundedVec<u8, <T as Config>::MaxValueLen>;
+
+	pub(crate) type RecentProofOf<T> = (
+		NamespaceOf<T>,
+		BoundedVec<u8, <T as Config>::MaxBytesInHash>,
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+	);
+
+	pub(crate) type ClaimInfoOf<T> = ClaimInfo<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T>,
+	>;
+
+	/// 存证的详细信息
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// 存证当前的持有者
+		pub owner: AccountId,
+		/// 最初创建存证并支付押金的账户, 转移所有权后保持不变
+		pub creator: AccountId,
+		/// 存证被创建时的区块号
+		pub block_number: BlockNumber,
+		/// 创建存证时从创建者处保留的押金, 移除存证时退还给创建者
+		pub deposit: Balance,
+	}
+
+	pub(crate) type ChallengeOf<T> = Challenge<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<u8, <T as Config>::MaxMetadataLength>,
+	>;
+
+	/// 对存证提出的质疑
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Challenge<AccountId, Balance, BlockNumber, Reason> {
+		/// 提出质疑的账户
+		pub challenger: AccountId,
+		/// 从质疑者处保留的保证金, 质疑被处理后退还
+		pub bond: Balance,
+		/// 质疑的理由
+		pub reason: Reason,
+		/// 管理员应当在此区块之前处理质疑
+		pub resolve_by: BlockNumber,
+	}
+
+	pub(crate) type PendingClaimOf<T> = PendingClaim<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxApprovers>,
+	>;
+
+	/// 等待审批人批准的存证
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PendingClaim<AccountId, BlockNumber, Approvers> {
+		/// 提议存证的账户, 存证生效后成为持有者并支付押金
+		pub proposer: AccountId,
+		/// 可以批准存证的审批人
+		pub approvers: Approvers,
+		/// 已经批准存证的审批人
+		pub approvals: Approvers,
+		/// 存证生效所需的批准数量
+		pub threshold: u32,
+		/// 在此区块之后未达到批准数量的提议失效
+		pub expires_at: BlockNumber,
+	}
+
+	/// 等待批准的存证的状态
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PendingClaimStatus {
+		/// 尚未达到批准数量, 仍然可以被批准
+		ThresholdNotMet,
+		/// 已经超过期限, 只能被移除
+		Expired,
+	}
+
+	/// 账户的存证统计
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ClaimStats {
+		/// 当前持有的存证数量
+		pub active: u32,
+		/// 创建过的存证总数, 包括已经被移除的存证
+		pub created: u32,
+		/// 持有的存证被撤销(包括被管理员或集体投票撤销)的总数, 过期或被删除的存证不计入
+		pub revoked: u32,
+	}
+
+	/// 命名空间的配置, 由管理员通过 `create_namespace` 创建
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct NamespaceInfo<AccountId> {
+		/// 命名空间的管理员, 可以撤销命名空间中的任意存证
+		pub admin: AccountId,
+		/// 命名空间中最多允许的存证数量
+		pub max_proofs: u32,
+		/// 命名空间中当前的存证数量
+		pub count: u32,
+	}
+
+	/// `Pallet::ensure_can_mutate` 检查的存证操作
+	pub(crate) enum ProofOp<'a, T: Config> {
+		/// 创建存证
+		Create,
+		/// 转移存证
+		Transfer(&'a NamespaceOf<T>, &'a BoundedVec<u8, T::MaxBytesInHash>),
+		/// 撤销存证
+		Revoke(&'a NamespaceOf<T>, &'a BoundedVec<u8, T::MaxBytesInHash>),
+		/// 由持有者或被授权的账户通过 `transfer_from` 转移存证
+		TransferFrom(&'a NamespaceOf<T>, &'a BoundedVec<u8, T::MaxBytesInHash>),
+		/// 将存证替换为新的哈希
+		Update(&'a NamespaceOf<T>, &'a BoundedVec<u8, T::MaxBytesInHash>),
+		/// 批量操作, 每个存证的授权由调用者分别检查, 无权操作的存证被跳过
+		Batch,
+	}
+
+	/// 生成存证时使用的哈希算法, 验证者据此从原始文件重新计算存证
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum HashAlgo {
+		/// Blake2b-256
+		Blake2_256,
+		/// SHA-256
+		Sha2_256,
+		/// Keccak-256
+		Keccak256,
+		/// 其他算法, 由应用自行约定编号. 未指定算法的存证视为 `Other(0)`
+		Other(u8),
+	}
+
+	impl Default for HashAlgo {
+		fn default() -> Self {
+			HashAlgo::Other(0)
+		}
+	}
+
+	impl HashAlgo {
+		/// 使用该算法计算 `data` 的哈希, 由应用自行约定的算法返回 `None`.
+		pub fn hash(&self, data: &[u8]) -> Option<[u8; 32]> {
+			use frame_support::sp_io::hashing;
+			match self {
+				HashAlgo::Blake2_256 => Some(hashing::blake2_256(data)),
+				HashAlgo::Sha2_256 => Some(hashing::sha2_256(data)),
+				HashAlgo::Keccak256 => Some(hashing::keccak_256(data)),
+				HashAlgo::Other(_) => None,
+			}
+		}
+	}
+
+	/// 当前的存储版本
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+	/// `list_proofs` 单次最多返回的存证数量, 避免过大的查询拖慢节点
+	pub const MAX_PROOFS_PAGE_SIZE: u32 = 1_000;
+
+	/// `proofs_exist` 单次最多查询的存证数量, 避免过大的查询拖慢节点
+	pub const MAX_PROOFS_PER_QUERY: u32 = 1_000;
+
+	/// 单个区块内最多推送的新存证数量
+	pub const MAX_NEW_CLAIMS: u32 = 1_000;
+
+	/// `supersession_chain` 最多回溯的存证数量
+	pub const MAX_SUPERSESSION_DEPTH: u32 = 100;
+
+	/// `proofs_in_range` 单次最多返回的存证数量, 避免过大的查询拖慢节点
+	pub const MAX_PROOFS_IN_RANGE: u32 = 1_000;
+
+	/// `verify_inclusion` 最多接受的 Merkle 路径长度, 足以覆盖 2^64 个叶子
+	pub const MAX_MERKLE_DEPTH: usize = 64;
+
+	/// Merkle 树中叶子节点哈希的前缀, 与内部节点区分以防止内部节点被当作叶子验证
+	pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+
+	/// Merkle 树中内部节点哈希的前缀
+	pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+	/// `create_claim_with_priority` 为腾出空间最多检查的存证数量,
+	/// 用于约束跳过不能被移除的存证的开销
+	pub const MAX_EVICTION_CANDIDATES: u32 = 16;
+
+	/// `on_initialize` 每个区块最多清除的 `CreationsThisBlock` 记录数量, 未被清除的旧记录
+	/// 不影响限流, 在之后的区块中继续清除
+	pub const MAX_CREATION_RECORDS_CLEARED: u32 = 1_000;
+
+	/// `clean_expired` 单次最多检查的过期区块数量, 用于约束没有过期存证的区块带来的开销
+	pub const MAX_EXPIRY_BLOCKS_PER_CLEAN: u32 = 1_000;
+
+	/// `rebuild_bloom` 单次最多加入布隆过滤器的存证数量
+	pub const MAX_BLOOM_REBUILD_BATCH: u32 = 1_000;
+
+	/// 每个存证在布隆过滤器中设置的位数
+	const BLOOM_HASHES: usize = 4;
+
+	/// 查看私有存证时签名内容的前缀, 参见 `Pallet::view_payload`
+	pub const VIEW_PAYLOAD_PREFIX: &[u8] = b"poe/view";
+
+	/// 代为创建存证时签名内容的前缀, 参见 `Pallet::signed_claim_payload`
+	pub const CREATE_PAYLOAD_PREFIX: &[u8] = b"poe/create";
 
 	// The struct on which we build all of our Pallet logic.
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// 因为这个 pallet 会发出事件，所以它取决于运行时对事件的定义。
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// 用于保留存证押金的货币
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// 创建一个存证需要保留的押金
+		#[pallet::constant]
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+		/// 用于约束存证的哈希的最小字节数, 用于拒绝空的或未经哈希的存证
+		#[pallet::constant]
+		type MinBytesInHash: Get<u32>;
 		/// 用于约束存证的哈希的最大字节数
 		type MaxBytesInHash: Get<u32>;
+		/// 用于约束存证命名空间的最大字节数. 空的命名空间是默认的命名空间
+		#[pallet::constant]
+		type MaxNamespaceLen: Get<u32>;
+		/// 用于约束存证描述信息的最大字节数
+		type MaxMetadataLength: Get<u32>;
+		/// 转移存证时附加的备注的最大字节数
+		#[pallet::constant]
+		type MaxMemoLen: Get<u32>;
+		/// 存证内容类型(MIME 类型, 例如 `application/pdf`)的最大字节数
+		#[pallet::constant]
+		type MaxMimeLen: Get<u32>;
+		/// 存证属性名的最大字节数
+		#[pallet::constant]
+		type MaxKeyLen: Get<u32>;
+		/// 存证属性值的最大字节数
+		#[pallet::constant]
+		type MaxValueLen: Get<u32>;
+		/// 单个存证最多可以设置的属性数量
+		#[pallet::constant]
+		type MaxAttributesPerProof: Get<u32>;
+		/// 存证标签的最大字节数
+		#[pallet::constant]
+		type MaxTagLen: Get<u32>;
+		/// 单个存证最多可以设置的标签数量
+		#[pallet::constant]
+		type MaxTagsPerProof: Get<u32>;
+		/// 单次批量创建存证时允许的最大存证数量
+		type MaxBatchSize: Get<u32>;
+		/// 存证的有效期(区块数), 为 0 时存证永不过期
+		#[pallet::constant]
+		type ClaimTtl: Get<Self::BlockNumber>;
+		/// 单个区块内最多允许过期的存证数量, 用于约束 `on_initialize` 的权重
+		#[pallet::constant]
+		type MaxExpiredPerBlock: Get<u32>;
+		/// `ExpiredProofs` 中最多保留的过期记录数量, 超出时最早的记录被移除.
+		/// 为 0 时不记录过期的存证, 重新声明时总是发出 `ClaimCreated` 事件
+		#[pallet::constant]
+		type MaxExpiredRecords: Get<u32>;
+		/// 是否在 `on_initialize` 中自动清理过期的存证. 为 `false` 时过期的存证保留在链上,
+		/// 直到有人调用 `clean_expired` 将其清理, 以获得更可预测的区块权重
+		#[pallet::constant]
+		type AutoExpire: Get<bool>;
+		/// 是否在 `on_idle` 中使用区块剩余的权重清理过期的存证, 不与用户的交易竞争区块权重.
+		/// 通常与为 `false` 的 `AutoExpire` 一起使用
+		#[pallet::constant]
+		type IdleExpire: Get<bool>;
+		/// 在存证的过期区块调度 `handle_expiry`, 例如 `traits::NamedExpiryScheduler`.
+		/// 调度成功的存证不进入 `ExpiryQueue`, 不受 `AutoExpire` 和 `IdleExpire` 影响, 也不会发出
+		/// `ClaimExpiringSoon` 事件. 使用 `()` 时所有存证都通过 `ExpiryQueue` 过期
+		type ExpiryScheduler: ExpiryScheduler<Self::BlockNumber>;
+		/// 存证过期前多少个区块发出 `ClaimExpiringSoon` 事件, 为 0 时不发出
+		#[pallet::constant]
+		type ExpiryWarningBlocks: Get<Self::BlockNumber>;
+		/// 单个区块内最多发出的 `ClaimExpiringSoon` 事件数量, 用于约束 `on_initialize` 的权重
+		#[pallet::constant]
+		type MaxExpiryWarningsPerBlock: Get<u32>;
+		/// 链上最多可以同时存在的存证数量, 存证被撤销或过期后会释放容量
+		#[pallet::constant]
+		type MaxProofs: Get<u32>;
+		/// 存证数量达到 `MaxProofs` 时, `create_claim_with_priority` 是否可以移除一个优先级更低的
+		/// 存证以腾出空间. 为 `false` 时不记录存证的优先级, 存证数量达到上限后无法创建新的存证
+		#[pallet::constant]
+		type ClaimEviction: Get<bool>;
+		/// 启用 `ClaimEviction` 时, 存证的每一级优先级需要额外保留的押金
+		#[pallet::constant]
+		type PriorityDeposit: Get<BalanceOf<Self>>;
+		/// 单个账户最多可以持有的存证数量
+		#[pallet::constant]
+		type MaxClaimsPerAccount: Get<u32>;
+		/// 需要审批的存证最多可以指定的审批人数量
+		#[pallet::constant]
+		type MaxApprovers: Get<u32>;
+		/// 需要审批的存证的提议有效期(区块数), 超过期限仍未达到批准数量的提议可以被任何人移除
+		#[pallet::constant]
+		type PendingClaimTtl: Get<Self::BlockNumber>;
+		/// 单个账户在一个区块内最多可以创建的存证数量, 为 0 时不限制
+		#[pallet::constant]
+		type MaxCreationsPerBlock: Get<u32>;
+		/// 单个存证最多可以记录的所有权转移次数
+		#[pallet::constant]
+		type MaxHistoryLength: Get<u32>;
+		/// 同一存证两次转移之间至少需要间隔的区块数, 为 0 时不限制
+		#[pallet::constant]
+		type TransferCooldown: Get<Self::BlockNumber>;
+		/// 共有存证最多可以拥有的持有者数量
+		#[pallet::constant]
+		type MaxOwners: Get<u32>;
+		/// 单个存证最多可以拥有的描述信息编辑者数量
+		#[pallet::constant]
+		type MaxMetadataEditors: Get<u32>;
+		/// 单个私有存证最多可以授权的查看者数量
+		#[pallet::constant]
+		type MaxViewers: Get<u32>;
+		/// 单个存证的接收者名单中最多可以包含的账户数量
+		#[pallet::constant]
+		type MaxAllowedRecipients: Get<u32>;
+		/// 是否由链下工作机将新创建的存证推送到节点配置的地址, 参见 `offchain` 模块
+		#[pallet::constant]
+		type ClaimNotifications: Get<bool>;
+		/// `RecentProofs` 中最多保留的最近创建的存证数量
+		#[pallet::constant]
+		type RecentBufferLen: Get<u32>;
+		/// 存证布隆过滤器的字节数, 为 0 时不维护布隆过滤器
+		#[pallet::constant]
+		type BloomBytes: Get<u32>;
+		/// 对存证提出质疑需要保留的保证金
+		#[pallet::constant]
+		type ChallengeBond: Get<BalanceOf<Self>>;
+		/// 发起存证转移时从发起者处保留的保证金, 转移被接收或撤回后退还. 为 0 时不保留
+		#[pallet::constant]
+		type TransferBond: Get<BalanceOf<Self>>;
+		/// 延长存证有效期时持有者需要支付的费用, 交给 `OnRenewalFee` 处理. 为 0 时免费
+		#[pallet::constant]
+		type RenewalFee: Get<BalanceOf<Self>>;
+		/// 管理员处理质疑的期限(区块数)
+		#[pallet::constant]
+		type ChallengePeriod: Get<Self::BlockNumber>;
+		/// 存证售出时支付给存证创建者的版税(基点, 10_000 为全部价格), 超过 10_000 时按 10_000 计算
+		#[pallet::constant]
+		type RoyaltyBps: Get<u32>;
+		/// 是否只允许 `AllowedCreators` 中的账户创建存证, 为 `false` 时任何账户都可以创建
+		#[pallet::constant]
+		type PermissionedCreation: Get<bool>;
+		/// 是否只允许 `IdentityProvider` 认为已登记身份的账户创建存证, 公链通常设为 `false`
+		#[pallet::constant]
+		type RequireIdentity: Get<bool>;
+		/// 查询账户是否已登记(例如经过 KYC 验证的)身份, 例如基于 `pallet_identity` 的实现
+		type IdentityProvider: IdentityProvider<Self::AccountId>;
+		/// 可以不经持有者同意强制撤销存证、暂停或恢复 pallet、管理存证创建者名单的管理员来源
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+		/// 可以通过集体投票撤销存证的来源, 例如 `pallet_collective` 的 `EnsureProportionAtLeast`
+		type CouncilOrigin: EnsureOrigin<Self::Origin>;
+		/// 可以通过 `create_claim_no_deposit` 免押金创建存证的来源, 例如登记机构等系统账户
+		type DepositWaiverOrigin: EnsureOrigin<Self::Origin>;
+		/// 用于记录存证创建时间的时间来源, 不需要记录时使用 `traits::NoTimestamp`
+		type UnixTime: UnixTime;
+		/// 存证被创建、转移或撤销时的回调, 不需要时使用 `()`
+		type OnClaimHandler: OnClaimEvent<Self::AccountId>;
+		/// 存证被强制撤销并罚没时, 被罚没的押金的去向, 直接销毁时使用 `()`
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// 延长存证有效期时收取的费用的去向, 例如国库. 直接销毁时使用 `()`
+		type OnRenewalFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// 存证作者对存证签名时使用的签名类型, 用于由他人代为提交存证
+		type AuthoritySignature: Verify<Signer = Self::AuthoritySigner> + Parameter;
+		/// 签名对应的公钥, 可以转换为存证作者的账户
+		type AuthoritySigner: IdentifyAccount<AccountId = Self::AccountId>;
+		/// 将提交的存证转换为规范形式, 作为存储中使用的键, 默认的 `()` 不做任何转换
+		type ProofNormalizer: ProofNormalizer<Self::MaxBytesInHash>;
+		/// 可以通过 `query_claim` 发起跨链存证查询的来源, 例如 `pallet_xcm::EnsureXcm`,
+		/// 成功时返回发起查询的位置
+		type QueryOrigin: EnsureOrigin<Self::Origin, Success = Self::QueryLocation>;
+		/// 发起跨链查询的位置, 例如 XCM 的 `MultiLocation`
+		type QueryLocation: Parameter;
+		/// 将跨链查询的结果发回发起查询的位置, 只通过事件返回结果时使用 `()`
+		type QueryResponder: QueryResponder<Self::QueryLocation>;
+		/// 可调度函数的权重信息
+		type WeightInfo: WeightInfo;
 	}
 
 
@@ -28,128 +447,5259 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// 当凭证被声明创建时，发出一个事件. [who, claim]
-		ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxBytesInHash>),
-		/// 当一个凭证声明被持有者撤销时，发出一个事件. [who, claim]
-		ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxBytesInHash>),
-		/// 当发送者转移持有权时，发出一个事件. [from, to, claim]
-		ClaimTransfered(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当凭证被声明创建时，发出一个事件. [who, namespace, claim, deposit, created_at]
+		ClaimCreated(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+			T::BlockNumber,
+		),
+		/// 当凭证声明被持有者或其撤销代理撤销时，发出一个事件.
+		/// [who, namespace, claim, creator, revoked_at]
+		ClaimRevoked(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::AccountId,
+			T::BlockNumber,
+		),
+		/// 已弃用, 请使用拼写正确的 `ClaimTransferred`. 该事件不再被发出,
+		/// 保留它只是为了可以继续解码历史区块中的事件.
+		/// [from, to, namespace, claim, transferred_at]
+		ClaimTransfered(
+			T::AccountId,
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::BlockNumber,
+		),
+		/// 当持有者设置存证的描述信息时，发出一个事件. [who, namespace, claim, version]
+		MetadataSet(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, u32),
+		/// 当存证到达有效期被自动清理时，发出一个事件. [owner, namespace, claim]
+		ClaimExpired(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当持有者延长存证有效期时，发出一个事件. [who, namespace, claim, expires_at]
+		ClaimRenewed(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::BlockNumber,
+		),
+		/// 当持有者授权第三方转移存证时，发出一个事件. [owner, spender, namespace, claim]
+		ApprovalSet(T::AccountId, T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证的转移授权被清除时，发出一个事件. [namespace, claim]
+		ApprovalCleared(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当持有者发起一个待接收的转移时，发出一个事件. [from, to, namespace, claim]
+		TransferOffered(
+			T::AccountId,
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+		),
+		/// 当接收者接受转移时，发出一个事件. [from, to, namespace, claim]
+		TransferAccepted(
+			T::AccountId,
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+		),
+		/// 当持有者撤回待接收的转移时，发出一个事件. [owner, namespace, claim]
+		TransferCancelled(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当一个不可转移(灵魂绑定)的存证被创建时，紧随 `ClaimCreated` 发出一个事件.
+		/// [who, namespace, claim]
+		SoulboundClaimCreated(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当一个凭证声明被管理员强制撤销时，发出一个事件. [namespace, claim]
+		ClaimForceRevoked(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当持有者将存证更新为新的哈希时，发出一个事件. [who, namespace, old_claim, new_claim]
+		ClaimUpdated(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+		),
+		/// 当账户被加入存证创建者名单时，发出一个事件. [who]
+		CreatorAdded(T::AccountId),
+		/// 当账户被移出存证创建者名单时，发出一个事件. [who]
+		CreatorRemoved(T::AccountId),
+		/// 当 pallet 被暂停或恢复时，发出一个事件. [paused]
+		PausedStateChanged(bool),
+		/// 当存证被锁定时，发出一个事件. [namespace, claim]
+		ClaimLocked(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证被解除锁定时，发出一个事件. [namespace, claim]
+		ClaimUnlocked(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证或已撤销存证的记录被彻底删除时，发出一个事件. [who, namespace, claim]
+		ClaimBurned(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当持有者添加一个撤销代理时，发出一个事件. [owner, delegate]
+		RevokeDelegateAdded(T::AccountId, T::AccountId),
+		/// 当持有者移除一个撤销代理时，发出一个事件. [owner, delegate]
+		RevokeDelegateRemoved(T::AccountId, T::AccountId),
+		/// 当发送者转移持有权时，发出一个事件. [from, to, namespace, claim, transferred_at, memo]
+		///
+		/// 取代拼写错误的 `ClaimTransfered`. 新的事件被添加在末尾, 以免改变其他事件的编码索引.
+		ClaimTransferred(
+			T::AccountId,
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::BlockNumber,
+			BoundedVec<u8, T::MaxMemoLen>,
+		),
+		/// 当持有者设置存证的标签时，发出一个事件. [who, namespace, claim]
+		TagsSet(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证被强制撤销且押金被罚没时，发出一个事件. [creator, amount]
+		ClaimSlashed(T::AccountId, BalanceOf<T>),
+		/// 当共有存证被创建时，发出一个事件. [creator, namespace, claim]
+		SharedClaimCreated(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当共有存证的一个持有者同意撤销存证时，发出一个事件. [who, namespace, claim]
+		RevokeApproved(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证通过集体投票被撤销时，发出一个事件. [namespace, claim]
+		ClaimRevokedByCouncil(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证被质疑时，发出一个事件. [challenger, namespace, claim, resolve_by]
+		ClaimChallenged(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::BlockNumber,
+		),
+		/// 当管理员处理质疑时，发出一个事件. [namespace, claim, upheld]
+		ChallengeResolved(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, bool),
+		/// 当批量创建时因超出 `MaxBytesInHash` 而跳过存证时，发出一个事件. [who, namespace, count]
+		OversizedProofsSkipped(T::AccountId, NamespaceOf<T>, u32),
+		/// 当为存证保留押金时，发出一个事件. [who, namespace, claim, amount]
+		DepositReserved(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+		),
+		/// 当存证的押金被解除保留时，发出一个事件. [who, namespace, claim, amount]
+		DepositUnreserved(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+		),
+		/// 当新版本的存证取代旧版本时，发出一个事件. [who, namespace, new_claim, old_claim]
+		ClaimSuperseded(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+		),
+		/// 当持有者出售存证时，发出一个事件. [seller, namespace, claim, price]
+		ClaimListed(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, BalanceOf<T>),
+		/// 当存证被售出时，发出一个事件. [seller, buyer, namespace, claim, price]
+		ClaimSold(
+			T::AccountId,
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+		),
+		/// 当持有者取消出售存证时，发出一个事件. [namespace, claim]
+		ClaimDelisted(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证售出时向创建者支付版税，发出一个事件. [creator, amount]
+		RoyaltyPaid(T::AccountId, BalanceOf<T>),
+		/// 当存证即将过期时，发出一个事件. [owner, namespace, claim, expires_at]
+		ClaimExpiringSoon(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::BlockNumber,
+		),
+		/// 当账户被管理员冻结时，发出一个事件. [who]
+		AccountFrozen(T::AccountId),
+		/// 当账户被管理员解冻时，发出一个事件. [who]
+		AccountUnfrozen(T::AccountId),
+		/// 当持有者设置或清除存证的内容类型时，发出一个事件. [who, namespace, claim, content_type]
+		ContentTypeSet(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BoundedVec<u8, T::MaxMimeLen>,
+		),
+		/// 当已过期的存证被重新声明时，代替 `ClaimCreated` 发出一个事件.
+		/// [who, previous_owner, namespace, claim, deposit, created_at]
+		ClaimReanchored(
+			T::AccountId,
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+			T::BlockNumber,
+		),
+		/// 当持有者为存证添加描述信息编辑者时，发出一个事件. [owner, namespace, claim, editor]
+		MetadataEditorAdded(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::AccountId,
+		),
+		/// 当持有者移除存证的描述信息编辑者时，发出一个事件. [owner, namespace, claim, editor]
+		MetadataEditorRemoved(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::AccountId,
+		),
+		/// 当 `clean_expired` 没有找到需要清理的过期存证时，发出一个事件. [who]
+		NothingToClean(T::AccountId),
+		/// 当持有者将账户加入存证的接收者名单时，发出一个事件. [owner, namespace, claim,
+		/// recipient]
+		RecipientAllowed(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::AccountId,
+		),
+		/// 当持有者将账户移出存证的接收者名单时，发出一个事件. [owner, namespace, claim,
+		/// recipient]
+		RecipientDisallowed(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			T::AccountId,
+		),
+		/// 当发起存证转移并保留保证金时，发出一个事件. [who, namespace, claim, amount]
+		TransferBondReserved(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+		),
+		/// 当存证转移被接收或撤回并退还保证金时，发出一个事件. [who, namespace, claim, amount]
+		TransferBondReleased(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+		),
+		/// 当存证为了给优先级更高的存证腾出空间而被移除时，发出一个事件. [owner, namespace, claim]
+		ClaimEvicted(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当需要审批的存证被提议时，发出一个事件. [proposer, namespace, claim, threshold,
+		/// expires_at]
+		ClaimProposed(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			u32,
+			T::BlockNumber,
+		),
+		/// 当审批人批准存证时，发出一个事件. [approver, namespace, claim, approvals]
+		PendingClaimApproved(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, u32),
+		/// 当等待批准的存证被提议者撤回或因过期被移除时，发出一个事件. [who, namespace, claim]
+		PendingClaimRemoved(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当持有者为延长存证有效期支付费用时，发出一个事件. [who, namespace, claim, fee]
+		RenewalFeePaid(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			BalanceOf<T>,
+		),
+		/// 当持有者设置存证的属性时，发出一个事件. [who, namespace, claim, key, value]
+		AttributeSet(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			AttributeKeyOf<T>,
+			AttributeValueOf<T>,
+		),
+		/// 当持有者清除存证的属性时，发出一个事件. [who, namespace, claim, key]
+		AttributeCleared(
+			T::AccountId,
+			NamespaceOf<T>,
+			BoundedVec<u8, T::MaxBytesInHash>,
+			AttributeKeyOf<T>,
+		),
+		/// 当批量操作结束时，发出一个事件, 记录实际处理和被跳过的数量. [processed, skipped]
+		BatchCompleted(u32, u32),
+		/// 当存证被固定时，发出一个事件. [namespace, claim]
+		ClaimPinned(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当存证被取消固定时，发出一个事件. [namespace, claim]
+		ClaimUnpinned(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当账户锁定自己所有存证的转移时，发出一个事件. [who]
+		TransfersLocked(T::AccountId),
+		/// 当账户解除自己所有存证的转移锁定时，发出一个事件. [who]
+		TransfersUnlocked(T::AccountId),
+		/// 当命名空间被创建时，发出一个事件. [admin, namespace, max_proofs]
+		NamespaceCreated(T::AccountId, NamespaceOf<T>, u32),
+		/// 当命名空间的管理员撤销存证时，发出一个事件. [admin, namespace, claim]
+		NamespaceClaimRevoked(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		/// 当布隆过滤器重建完成时，发出一个事件.
+		BloomRebuilt,
+		/// 当存证被设置为私有或公开时，发出一个事件. [namespace, claim, private]
+		ClaimPrivacySet(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, bool),
+		/// 当持有者授权账户查看私有存证时，发出一个事件. [owner, namespace, claim, viewer]
+		ViewGranted(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, T::AccountId),
+		/// 当持有者取消账户查看私有存证的授权时，发出一个事件. [owner, namespace, claim, viewer]
+		ViewRevoked(T::AccountId, NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, T::AccountId),
+		/// 当命名空间的存证数量上限被修改时，发出一个事件. [namespace, max_proofs]
+		NamespaceMaxProofsSet(NamespaceOf<T>, u32),
+		/// 当跨链存证查询被响应时，发出一个事件. [query_id, response]
+		ClaimQueryAnswered(u64, Vec<u8>),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		/// 当前凭证已被声明.
+		/// 当前凭证已被声明且仍然有效. 已被撤销的凭证返回 `AlreadyRevoked`.
 		ProofAlreadyClaimed,
 		/// 当前凭证不存在，无法被更改。
 		NoSuchProof,
 		/// 存证已经被其他持有者声明，所以调用者无法进行更改
 		NotProofOwner,
+		/// 目标区块内过期的存证数量已达上限, 请稍后再试
+		TooManyExpiries,
+		/// 当前存证没有有效期, 无需延长
+		ClaimDoesNotExpire,
+		/// 接收存证的账户持有的存证数量已达上限
+		ExceededMaxClaims,
+		/// 调用者的可用余额不足以支付存证押金
+		InsufficientDeposit,
+		/// 存证已授权给其他账户转移, 调用者既不是存证的持有者, 也不是被授权的账户
+		NotApproved,
+		/// 当前存证没有待接收的转移
+		NoPendingTransfer,
+		/// 调用者不是待接收转移的接收者
+		NotTransferRecipient,
+		/// 存证是灵魂绑定的, 无法被转移
+		ClaimNotTransferable,
+		/// pallet 已被暂停, 暂时无法修改存证
+		PalletPaused,
+		/// 不能将存证转移给当前的持有者自己
+		CannotTransferToSelf,
+		/// 存证的转移记录已达到 `MaxHistoryLength`, 无法再转移
+		HistoryFull,
+		/// 调用者不在存证创建者名单中
+		NotAuthorizedToCreate,
+		/// 存证的长度不在 `MinBytesInHash` 和 `MaxBytesInHash` 之间
+		InvalidProofLength,
+		/// 签名不是存证作者对存证的有效签名
+		BadSignature,
+		/// 调用者在当前区块内创建的存证数量已达到 `MaxCreationsPerBlock`
+		RateLimited,
+		/// 存证已被撤销, 在被彻底删除之前无法再次声明. 撤销者和撤销时的区块号可以通过
+		/// `revocation_info` 查询
+		AlreadyRevoked,
+		/// 链上的存证数量已达到 `MaxProofs`
+		StorageFull,
+		/// 存证的标签数量超过了 `MaxTagsPerProof`
+		TooManyTags,
+		/// 存证已被锁定, 在解除锁定之前无法被转移、撤销或更新
+		ClaimLocked,
+		/// 存证没有被锁定
+		ClaimNotLocked,
+		/// 距离存证上一次被转移还不到 `TransferCooldown` 个区块
+		TransferTooSoon,
+		/// 存证的长度超过了 `MaxBytesInHash`
+		ProofTooLong,
+		/// 调用者不是共有存证的持有者之一
+		NotAnOwner,
+		/// 共有存证的持有者数量超过了 `MaxOwners`
+		TooManyOwners,
+		/// 账户已经是共有存证的持有者
+		DuplicateOwner,
+		/// 共有存证的所有持有者都同意之后才能被撤销
+		RevokeNotApproved,
+		/// 存证已经有一个尚未处理的质疑
+		ChallengeInProgress,
+		/// 存证没有尚未处理的质疑
+		NoSuchChallenge,
+		/// 质疑者的余额不足以保留质疑保证金
+		InsufficientBond,
+		/// 存证已经被另一个存证取代
+		AlreadySuperseded,
+		/// 存证没有在出售
+		NotForSale,
+		/// 买方的余额不足以支付存证的价格
+		InsufficientBalance,
+		/// 没有该编号的存证
+		NoSuchClaimId,
+		/// 账户已被冻结, 无法创建、转移、撤销或接收存证
+		AccountFrozen,
+		/// 启用 `RequireIdentity` 时, 没有登记身份的账户无法创建存证
+		IdentityRequired,
+		/// 存证的描述信息编辑者数量已达到上限
+		TooManyEditors,
+		/// 该账户不是存证的描述信息编辑者
+		NotAnEditor,
+		/// 存证已经过期, 正在等待被清理, 无法再延长有效期
+		ClaimAlreadyExpired,
+		/// 存证设置了接收者名单, 而接收者不在名单中
+		RecipientNotAllowed,
+		/// 存证的接收者名单已满
+		TooManyRecipients,
+		/// 该账户不在存证的接收者名单中
+		NotAllowedRecipient,
+		/// 命名空间超出 `MaxNamespaceLen`
+		NamespaceTooLong,
+		/// 存证正在等待审批人批准
+		ClaimPending,
+		/// 没有等待批准的存证
+		NoSuchPendingClaim,
+		/// 批准数量为 0 或超过审批人数量
+		InvalidThreshold,
+		/// 审批人不能重复
+		DuplicateApprover,
+		/// 调用者不是该存证的审批人
+		NotAnApprover,
+		/// 调用者已经批准过该存证
+		AlreadyApproved,
+		/// 等待批准的存证已经超过期限
+		PendingClaimExpired,
+		/// 等待批准的存证尚未过期, 只有提议者可以将其撤回
+		PendingClaimNotExpired,
+		/// 延长的区块数为 0 或超过 `ClaimTtl`
+		InvalidRenewalPeriod,
+		/// 存证的属性数量已达到 `MaxAttributesPerProof`
+		TooManyAttributes,
+		/// 存证没有设置该属性
+		NoSuchAttribute,
+		/// 存证已经被固定
+		AlreadyPinned,
+		/// 存证没有被固定
+		NotPinned,
+		/// 持有者已经锁定了自己所有存证的转移
+		TransfersLocked,
+		/// 持有者没有锁定自己存证的转移
+		TransfersNotLocked,
+		/// 命名空间中的存证数量已达到上限
+		NamespaceFull,
+		/// 命名空间已经被创建
+		NamespaceAlreadyExists,
+		/// 命名空间中已经有存证, 无法被创建
+		NamespaceInUse,
+		/// 调用者不是命名空间的管理员
+		NotNamespaceAdmin,
+		/// 没有启用布隆过滤器
+		BloomDisabled,
+		/// 存证授权的查看者数量已达到上限
+		TooManyViewers,
+		/// 账户不是存证授权的查看者
+		NotAViewer,
+		/// 签名内容中的随机数与签名者当前的随机数不一致, 签名已被使用过或不是最新的
+		BadNonce,
+		/// 存证的出售价格高于买方接受的最高价格
+		PriceTooHigh,
+		/// 默认的空命名空间不能被创建为受管理的命名空间
+		DefaultNamespace,
+		/// 命名空间还没有被创建
+		NoSuchNamespace,
 	}
 
+	/// `Proofs` 中存证键使用的哈希算法.
+	///
+	/// 存证本身已经是内容的密码学哈希, 难以被用来构造不平衡的存储树, 因此对存储开销敏感的链
+	/// 可以开启 `twox-proof-keys` 特性改用更便宜的 `Twox64Concat`, 默认使用 `Blake2_128Concat`.
+	/// 已有存证的链切换该特性时需要执行 [`migrations::proof_hasher::migrate`].
+	#[cfg(not(feature = "twox-proof-keys"))]
+	pub type ProofKeyHasher = Blake2_128Concat;
+	/// `Proofs` 中存证键使用的哈希算法, 见 `twox-proof-keys` 特性.
+	#[cfg(feature = "twox-proof-keys")]
+	pub type ProofKeyHasher = Twox64Concat;
+
+	#[pallet::storage]
+	/// Maps each proof within a namespace to its owner, block number when the proof was made and
+	/// reserved deposit
+	pub(super) type Proofs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		ProofKeyHasher,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		ClaimInfoOf<T>,
+		OptionQuery,
+	>;
+
+	/// 存证的描述信息，由存证的持有者设置
+	#[pallet::storage]
+	pub(super) type ProofMetadata<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<u8, T::MaxMetadataLength>,
+		OptionQuery,
+	>;
+
+	/// 存证的描述信息被设置的次数, 第一次设置后为 1
+	#[pallet::storage]
+	pub(super) type MetadataVersion<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		u32,
+		ValueQuery,
+	>;
+
+	/// 存证的标签, 由存证的持有者设置
+	#[pallet::storage]
+	pub(super) type ProofTags<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<TagOf<T>, T::MaxTagsPerProof>,
+		ValueQuery,
+	>;
+
+	/// 按命名空间和标签索引的存证, 用于按标签查找存证
+	#[pallet::storage]
+	pub(super) type TagIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(NamespaceOf<T>, TagOf<T>),
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(),
+		OptionQuery,
+	>;
+
+	/// 存证的过期区块号
+	#[pallet::storage]
+	pub(super) type ProofExpiry<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// 已经发出过即将过期提醒的存证, 存证被续期后清除, 以便在新的有效期结束前再次提醒
+	#[pallet::storage]
+	pub(super) type ExpiryWarned<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(),
+	>;
+
+	/// 按过期区块号分组的存证. `AutoExpire` 为 `true` 时在对应区块的 `on_initialize` 中被清理,
+	/// 否则由 `on_idle` 或 `clean_expired` 从 `ExpiryCursor` 开始清理.
+	///
+	/// 由 `ExpiryScheduler` 调度的存证不在这里的队列中, 移除、续期和重新声明存证时同时取消
+	/// 对应的调度任务. 创世区块中预置的存证总是加入这里的队列
+	#[pallet::storage]
+	pub(super) type ExpiryQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>), T::MaxExpiredPerBlock>,
+		ValueQuery,
+	>;
+
+	/// `clean_expired` 和 `on_idle` 下一次开始检查的过期区块号, 之前的区块中过期的存证都已被清理
+	#[pallet::storage]
+	pub(super) type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// 存证的优先级, 只在启用 `ClaimEviction` 时记录. 存证数量达到上限时优先级低的存证会被移除
+	#[pallet::storage]
+	pub(super) type ProofPriority<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		u8,
+		OptionQuery,
+	>;
+
+	/// 按优先级索引的存证, 用于在不遍历全部存证的情况下找到优先级最低的存证
+	#[pallet::storage]
+	pub(super) type PriorityIndex<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u8,
+		Blake2_128Concat,
+		(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		(),
+		OptionQuery,
+	>;
+
+	/// 每个账户当前持有的存证及其命名空间
+	#[pallet::storage]
+	pub(super) type ClaimsOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>), T::MaxClaimsPerAccount>,
+		ValueQuery,
+	>;
+
+	/// 每个账户的存证统计, 共有存证只计入 `ClaimInfo` 中记录的持有者
+	#[pallet::storage]
+	pub(super) type AccountStats<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ClaimStats, ValueQuery>;
+
+	/// 已创建的命名空间及其管理员和存证数量上限, 未创建的命名空间不受限制
+	#[pallet::storage]
+	pub(super) type NamespaceConfig<T: Config> =
+		StorageMap<_, Blake2_128Concat, NamespaceOf<T>, NamespaceInfo<T::AccountId>, OptionQuery>;
+
+	/// 被持有者授权可以转移存证的账户
+	#[pallet::storage]
+	pub(super) type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// 发起存证转移时保留的保证金, 值为发起者和保证金金额. 待接收的转移被移除时退还
+	#[pallet::storage]
+	pub(super) type TransferBonds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// 等待接收者确认的存证转移, 值为接收者
+	#[pallet::storage]
+	pub(super) type PendingTransfers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// 不可转移(灵魂绑定)的存证
+	#[pallet::storage]
+	pub(super) type Soulbound<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(),
+		OptionQuery,
+	>;
+
+	/// 被固定的存证, 过期时不会被清理, 直到被取消固定
+	#[pallet::storage]
+	pub(super) type Pinned<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(),
+		OptionQuery,
+	>;
+
+	/// 存证被创建时的 Unix 时间戳(毫秒), 时间来源不可用时不记录
+	#[pallet::storage]
+	pub(super) type ProofTimestamps<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		u64,
+		OptionQuery,
+	>;
+
+	/// 存证的所有权转移记录, 按时间顺序记录每次转移后的持有者及转移时的区块号
+	#[pallet::storage]
+	pub(super) type TransferHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<(T::AccountId, T::BlockNumber), T::MaxHistoryLength>,
+		ValueQuery,
+	>;
+
+	/// 生成存证时使用的哈希算法, 没有记录时视为 `HashAlgo::Other(0)`
+	#[pallet::storage]
+	pub(super) type ProofHashAlgo<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		HashAlgo,
+	>;
+
+	/// 存证内容的 MIME 类型, 例如 `application/pdf`, 供前端展示存证对应的文件. 未设置时不记录
+	#[pallet::storage]
+	pub(super) type ContentTypes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<u8, T::MaxMimeLen>,
+	>;
+
+	/// 存证的属性, 例如 `issuer` 为 `Acme`. 第一个键为命名空间和存证, 第二个键为属性名
+	#[pallet::storage]
+	pub(super) type Attributes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		Blake2_128Concat,
+		AttributeKeyOf<T>,
+		AttributeValueOf<T>,
+	>;
+
+	/// 存证已设置的属性数量, 用于限制 `MaxAttributesPerProof`
+	#[pallet::storage]
+	pub(super) type AttributeCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		u32,
+		ValueQuery,
+	>;
+
+	/// 存证最近一次被转移时的区块号, 只在 `TransferCooldown` 不为 0 时记录
+	#[pallet::storage]
+	pub(super) type LastTransferAt<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		T::BlockNumber,
+	>;
+
+	/// 共有存证的全部持有者, 第一个持有者与 `Proofs` 中记录的持有者相同. 普通存证没有记录
+	#[pallet::storage]
+	pub(super) type SharedOwners<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<T::AccountId, T::MaxOwners>,
+	>;
+
+	/// 持有者授权的描述信息编辑者, 编辑者只能设置存证的描述信息. 存证被撤销或转移给新的持有者时清空
+	#[pallet::storage]
+	pub(super) type MetadataEditors<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<T::AccountId, T::MaxMetadataEditors>,
+		ValueQuery,
+	>;
+
+	/// 存证只能被转移给的账户名单, 为空时可以转移给任何账户. 名单随存证转移给新的持有者
+	#[pallet::storage]
+	pub(super) type TransferAllowlist<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<T::AccountId, T::MaxAllowedRecipients>,
+		ValueQuery,
+	>;
+
+	/// 新版本的存证所取代的旧版本存证
+	#[pallet::storage]
+	pub(super) type Supersedes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<u8, T::MaxBytesInHash>,
+	>;
+
+	/// 已被取代的存证及取代它的新版本存证, 被取代的存证仍然有效
+	#[pallet::storage]
+	pub(super) type SupersededBy<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<u8, T::MaxBytesInHash>,
+	>;
+
+	/// 正在出售的存证及其卖方和价格, 存证被转移或移除时自动取消
+	#[pallet::storage]
+	pub(super) type Listings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(T::AccountId, BalanceOf<T>),
+	>;
+
+	/// 存证尚未处理的质疑, 每个存证同时最多有一个
+	#[pallet::storage]
+	pub(super) type Challenges<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		ChallengeOf<T>,
+	>;
+
+	/// 等待审批人批准的存证, 达到批准数量后存证生效并被移除
+	#[pallet::storage]
+	pub(super) type PendingClaims<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		PendingClaimOf<T>,
+	>;
+
+	/// 提议需要审批的存证时保留的押金, 值为提议者和押金金额. 存证生效或提议被移除时退还
+	#[pallet::storage]
+	pub(super) type PendingClaimDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// 已经同意撤销共有存证的持有者
+	#[pallet::storage]
+	pub(super) type RevokeApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<T::AccountId, T::MaxOwners>,
+		ValueQuery,
+	>;
+
+	/// 被锁定的存证, 例如存在法律纠纷而需要暂时冻结的存证
+	#[pallet::storage]
+	pub(super) type LockedProofs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(),
+		OptionQuery,
+	>;
+
+	/// 已过期且尚未被重新声明的存证, 值为过期时的持有者. 存证被重新声明时用于记录其来源.
+	/// 最多保留 `MaxExpiredRecords` 条记录, 超出时按过期的顺序移除最早的记录
+	#[pallet::storage]
+	pub(super) type ExpiredProofs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// 按过期顺序排列的过期记录, 键为记录的序号. 记录被重新声明后留下的条目在被移除时跳过
+	#[pallet::storage]
+	pub(super) type ExpiredRecordQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>),
+		OptionQuery,
+	>;
+
+	/// 过期记录在 `ExpiredRecordQueue` 中的序号
+	#[pallet::storage]
+	pub(super) type ExpiredRecordIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		u64,
+		OptionQuery,
+	>;
+
+	/// `ExpiredRecordQueue` 中最早的条目和下一个条目的序号
+	#[pallet::storage]
+	pub(super) type ExpiredRecordRange<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	/// 已被持有者撤销的存证, 值为撤销者和撤销时的区块号. 被撤销的存证在被彻底删除之前无法再次声明
+	#[pallet::storage]
+	pub(super) type RevokedProofs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(T::AccountId, T::BlockNumber),
+		OptionQuery,
+	>;
+
+	/// 被撤销的存证仍然保留的押金, 值为创建者和押金金额. 撤销记录被 `burn_claim` 删除时退还
+	#[pallet::storage]
+	pub(super) type TombstoneDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// 持有者授权的撤销代理, 撤销代理可以撤销持有者的任意存证
+	#[pallet::storage]
+	pub(super) type RevokeDelegates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// 启用 `PermissionedCreation` 时允许创建存证的账户
+	#[pallet::storage]
+	pub(super) type AllowedCreators<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// 被管理员冻结的账户, 例如违反服务条款而被暂停使用的账户.
+	/// 冻结不影响账户已持有的存证, 只阻止账户发起的存证操作以及作为转移的接收者.
+	#[pallet::storage]
+	pub(super) type FrozenAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// 账户下一个由其签名授权的操作需要使用的随机数, 每使用一次递增, 用于防止签名被重放
+	#[pallet::storage]
+	pub(super) type AccountNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// 持有者自己锁定了转移的账户, 锁定期间该账户持有的存证都无法被转出, 但仍然可以被撤销
+	#[pallet::storage]
+	pub(super) type AccountTransferLock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// 每个账户最近一次作为持有者创建存证的区块号和在该区块内创建的存证数量.
+	/// 区块号不是当前区块的记录视为没有创建过存证, 由 `on_initialize` 分批清除
+	#[pallet::storage]
+	pub(super) type CreationsThisBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::BlockNumber, u32), ValueQuery>;
+
+	/// 当前区块内创建的存证, 供链下工作机推送, 在每个区块的 `on_initialize` 中被清空.
+	/// 只在 `ClaimNotifications` 为 `true` 时记录
+	#[pallet::storage]
+	pub(super) type NewClaims<T: Config> = StorageValue<
+		_,
+		BoundedVec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>), ConstU32<MAX_NEW_CLAIMS>>,
+		ValueQuery,
+	>;
+
+	/// 最近创建的存证及其创建者和创建时的区块号, 按创建顺序排列, 超出 `RecentBufferLen`
+	/// 时丢弃最早的存证. 被撤销或过期的存证不会从中移除, 由 `recent_proofs` 在查询时过滤
+	#[pallet::storage]
+	pub(super) type RecentProofs<T: Config> =
+		StorageValue<_, BoundedVec<RecentProofOf<T>, T::RecentBufferLen>, ValueQuery>;
+
+	/// 存证的布隆过滤器, 用于在不读取 `Proofs` 的情况下确定存证一定不存在. 创建存证时设置对应的位,
+	/// 撤销或过期的存证无法清除对应的位, 误判率会随之升高, 可以通过 `rebuild_bloom` 重建.
+	/// 为空时表示过滤器尚未建立, 所有存证都被视为可能存在
+	#[pallet::storage]
+	pub(super) type ProofBloom<T: Config> =
+		StorageValue<_, BoundedVec<u8, T::BloomBytes>, ValueQuery>;
+
+	/// 正在重建的布隆过滤器, 以及最后一个已经加入过滤器的存证, 重建完成后替换 `ProofBloom`
+	#[pallet::storage]
+	pub(super) type BloomRebuild<T: Config> = StorageValue<
+		_,
+		(
+			BoundedVec<u8, T::BloomBytes>,
+			Option<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)>,
+		),
+		OptionQuery,
+	>;
+
+	/// 被持有者设置为私有的存证. 运行时 API 的查询把调用者视为匿名账户, 不返回私有的存证及其
+	/// 描述信息、属性等关联数据, 持有者和被授权的查看者需要通过 `get_proof_info_signed` 查看.
+	/// 跨链查询同样不返回私有的存证. `Pallet::verify_claim` 等供其他 pallet 调用的函数不做过滤.
+	///
+	/// 链上存储本身仍然是公开的, 任何人都可以直接读取 `Proofs` 等存储或遍历全部存证,
+	/// 这只是查询接口层面的隐私, 不是密码学意义上的保护.
+	/// 需要保密的内容不应上链, 存证本身也应当是无法被猜测原文的哈希
+	#[pallet::storage]
+	pub(super) type PrivateProofs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		(),
+		OptionQuery,
+	>;
+
+	/// 持有者授权查看私有存证的账户. 存证被撤销或转移给新的持有者时清空
+	#[pallet::storage]
+	pub(super) type ViewPermissions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		BoundedVec<T::AccountId, T::MaxViewers>,
+		ValueQuery,
+	>;
+
+	/// 当前存在的存证总数
+	#[pallet::storage]
+	pub(super) type ProofCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// 当前存在的存证和撤销记录保留的押金总额, 始终等于 `Proofs` 和 `TombstoneDeposits` 中
+	/// 记录的押金之和, 用于与账户的保留余额对账
+	#[pallet::storage]
+	pub(super) type TotalReservedDeposits<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// 下一个被创建的存证的编号. 编号只增不减, 存证被移除后其编号也不会被重新使用
+	#[pallet::storage]
+	pub(super) type NextClaimId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// 存证编号对应的命名空间和存证
+	#[pallet::storage]
+	pub(super) type ClaimIdToProof<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)>;
+
+	/// 存证的编号
+	#[pallet::storage]
+	pub(super) type ProofIds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxBytesInHash>,
+		u64,
+	>;
+
+	/// pallet 是否已被暂停. 暂停期间所有存证的修改操作都会失败, 管理员的强制撤销除外.
 	#[pallet::storage]
-    /// Maps each proof to its owner and block number when the proof was made
-    pub(super) type Proofs<T: Config> = StorageMap<
-        _,
-        Blake2_128Concat,
-        BoundedVec<u8, T::MaxBytesInHash>,
-        (T::AccountId, T::BlockNumber),
-        OptionQuery,
-    >;
+	pub(super) type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// 链启动时预置的存证及其命名空间和持有者
+		pub proofs: Vec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, T::AccountId)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { proofs: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			// 预置的存证以区块号 0 作为创建区块, 且不需要押金
+			let genesis_block = T::BlockNumber::zero();
+			let ttl = T::ClaimTtl::get();
+
+			assert!(
+				self.proofs.len() as u32 <= T::MaxProofs::get(),
+				"Genesis proofs exceed MaxProofs"
+			);
+
+			// 预置的存证与调用创建的存证一样以规范化后的形式存储
+			let proofs: Vec<_> = self
+				.proofs
+				.iter()
+				.map(|(namespace, proof, owner)| {
+					(namespace.clone(), T::ProofNormalizer::normalize(proof.clone()), owner.clone())
+				})
+				.collect();
+
+			for (namespace, proof, owner) in &proofs {
+				assert!(
+					!Proofs::<T>::contains_key(namespace, proof),
+					"Duplicate proof in genesis config"
+				);
+				assert!(
+					Pallet::<T>::ensure_valid_proof_length(proof).is_ok(),
+					"Proof shorter than MinBytesInHash in genesis config"
+				);
+
+				if !ttl.is_zero() {
+					let expires_at = genesis_block.saturating_add(ttl);
+					ExpiryQueue::<T>::try_mutate(expires_at, |queue| {
+						queue.try_push((namespace.clone(), proof.clone()))
+					})
+					.expect("Genesis proofs exceed MaxExpiredPerBlock");
+					ProofExpiry::<T>::insert(namespace, proof, expires_at);
+				}
+
+				ClaimsOf::<T>::try_mutate(owner, |claims| {
+					claims.try_push((namespace.clone(), proof.clone()))
+				})
+				.expect("Genesis proofs exceed MaxClaimsPerAccount");
+				AccountStats::<T>::mutate(owner, |stats| {
+					stats.active = stats.active.saturating_add(1);
+					stats.created = stats.created.saturating_add(1);
+				});
+				Proofs::<T>::insert(
+					namespace,
+					proof,
+					ClaimInfo {
+						owner: owner.clone(),
+						creator: owner.clone(),
+						block_number: genesis_block,
+						deposit: Zero::zero(),
+					},
+				);
+				ProofCount::<T>::mutate(|count| *count = count.saturating_add(1));
+				Pallet::<T>::assign_claim_id(namespace, proof);
+			}
+
+			// 从预置的存证建立布隆过滤器
+			if T::BloomBytes::get() > 0 {
+				let mut filter = Pallet::<T>::empty_bloom();
+				for (namespace, proof, _) in &proofs {
+					Pallet::<T>::bloom_insert(&mut filter, namespace, proof);
+				}
+				ProofBloom::<T>::put(filter);
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// 清除最多 `MAX_CREATION_RECORDS_CLEARED` 条之前区块的存证创建计数,
+		/// 清理在当前区块过期的存证并退还押金, 并提醒 `ExpiryWarningBlocks` 个区块后将要过期的
+		/// 存证的持有者.
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			// 钩子在交易之前执行, 此时的记录都来自之前的区块
+			let cleared =
+				match CreationsThisBlock::<T>::remove_all(Some(MAX_CREATION_RECORDS_CLEARED)) {
+					KillStorageResult::AllRemoved(count) |
+					KillStorageResult::SomeRemaining(count) => count,
+				};
+			if T::ClaimNotifications::get() {
+				NewClaims::<T>::kill();
+			}
+
+			// 不自动清理时, 过期的存证留在过期队列中等待 `clean_expired`
+			let expired =
+				if T::AutoExpire::get() { ExpiryQueue::<T>::take(n) } else { Default::default() };
+			let count = expired.len() as u32;
+
+			for (namespace, proof) in expired {
+				Self::expire_claim(&namespace, &proof);
+			}
+
+			let warned = Self::warn_expiring(n);
+
+			T::WeightInfo::on_initialize(count, warned, cleared)
+		}
+
+		/// 启用 `IdleExpire` 时, 使用区块剩余的权重从 `ExpiryCursor` 开始清理已经过期的存证,
+		/// 剩余的权重不足时停止, 下一个区块从停下的位置继续. 返回实际消耗的权重.
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let base = T::WeightInfo::clean_expired(0);
+			if !T::IdleExpire::get() || remaining_weight <= base {
+				return 0
+			}
+
+			let (cleaned, visited) = Self::sweep_expired(
+				T::MaxExpiredPerBlock::get(),
+				MAX_EXPIRY_BLOCKS_PER_CLEAN,
+				remaining_weight - base,
+			);
+			T::WeightInfo::clean_expired(cleaned)
+				.saturating_add(T::DbWeight::get().reads(visited as Weight))
+		}
+
+		/// 将当前区块内创建的存证推送到节点配置的地址.
+		fn offchain_worker(n: T::BlockNumber) {
+			if T::ClaimNotifications::get() {
+				crate::offchain::notify_new_claims::<T>(n);
+			}
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			crate::migrations::pre_upgrade::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			crate::migrations::post_upgrade::<T>()
+		}
+	}
 
 	// 可调度函数允许用户与 pallet 交互并调用状态更改。
 	// 这些函数具体化为 extrinsics(外部交易)，通常被比作事务
 	// 可调度函数必须用权重 weight 注释，并且必须返回调度结果。
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        #[pallet::weight(1_000)]
-        pub fn create_claim(
-            origin: OriginFor<T>,
-            proof: BoundedVec<u8, T::MaxBytesInHash>,
-        ) -> DispatchResult {
-            // 检查 extrinsics 是否已签名，并找到签名者.
-            // 如果 extrinsics 未进行名，此函数将返回一个错误
-            // https://docs.substrate.io/v3/runtime/origins
-            let sender = ensure_signed(origin)?;
-
-            // 验证指定的存证是否尚未声明。
-            ensure!(!Proofs::<T>::contains_key(&proof), Error::<T>::ProofAlreadyClaimed);
-
-            // 从 FRAME System pallet 获取区块号.
-            let current_block = <frame_system::Pallet<T>>::block_number();
-
-			// 存储存证中的 发送者 和 区块号
-            Proofs::<T>::insert(&proof, (&sender, current_block));
-
-     		// 发出一个存证被创建的事件
-            Self::deposit_event(Event::ClaimCreated(sender, proof));
-
-            Ok(())
-        }
-
-
-		#[pallet::weight(1_000)]
-        pub fn transfer_claim(
-            origin: OriginFor<T>,
-            account: T::AccountId,
-            proof: BoundedVec<u8, T::MaxBytesInHash>,
-        ) -> DispatchResult {
-            // 检查 extrinsics 是否已签名，并找到签名者.
-            // 如果 extrinsics 未进行名，此函数将返回一个错误
-            let sender = ensure_signed(origin)?;
-
-			// 验证指定的存证是否被声明。
-			ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
-
-            // 获取创建者信息.
-            // Panic 条件: 无法设置一个 `None` 持有者, 因此总是需要使用 unwrap 包裹.
-            let (owner, _) = Proofs::<T>::get(&proof).expect("All proofs must have an owner!");
-
-            // 验证函数调用的发起者是否拥有存证的所有权.
-            ensure!(sender == owner, Error::<T>::NotProofOwner);
-
-			// 从区块中转移存证所有权
-            Proofs::<T>::mutate(&proof, |values| {
-				match values {
-					Some(value) => {
-						value.0 = account.clone();
-					},
-					// 不做任何事情，因为 line 101 已经进行过存证存在性校验
-					_ =>  (),
-				};
-			});
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// 创建一个存证, 并记录生成存证时使用的哈希算法 `algo`.
+		/// 权重为 `WeightInfo::create_claim(l)`, 其中 `l` 是存证的字节数:
+		/// 在固定的基础权重之上, 每个字节按基准测试得到的系数线性增加权重, 以反映写入存储的开销.
+		#[pallet::weight(T::WeightInfo::create_claim(proof.len() as u32))]
+		pub fn create_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			algo: HashAlgo,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			// 如果 extrinsics 未进行名，此函数将返回一个错误
+			// https://docs.substrate.io/v3/runtime/origins
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+			Self::ensure_unclaimed(&namespace, &proof)?;
+
+			Self::do_create_claim(&sender, &sender, &namespace, proof.clone())?;
+			if algo != HashAlgo::default() {
+				ProofHashAlgo::<T>::insert(&namespace, &proof, algo);
+			}
+
+			Ok(().into())
+		}
+
+		/// 与 `create_claim` 相同, 同时记录存证内容的 MIME 类型 `content_type`.
+		#[pallet::weight(
+			T::WeightInfo::create_claim(proof.len() as u32)
+				.saturating_add(T::DbWeight::get().writes(1))
+		)]
+		pub fn create_claim_with_content_type(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			algo: HashAlgo,
+			content_type: BoundedVec<u8, T::MaxMimeLen>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			// 存证只规范化一次, `ContentTypes` 使用与 `Proofs` 相同的键
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+			Self::ensure_unclaimed(&namespace, &proof)?;
+
+			Self::do_create_claim(&sender, &sender, &namespace, proof.clone())?;
+			if algo != HashAlgo::default() {
+				ProofHashAlgo::<T>::insert(&namespace, &proof, algo);
+			}
+			if !content_type.is_empty() {
+				ContentTypes::<T>::insert(&namespace, &proof, content_type);
+			}
+
+			Ok(().into())
+		}
+
+		/// 与 `create_claim` 相同, 同时记录存证的优先级 `priority`. 启用 `ClaimEviction` 且存证数量
+		/// 已达到 `MaxProofs` 时, 会移除一个优先级低于 `priority` 的存证(优先级最低的优先)并将押金
+		/// 退还给其创建者, 以腾出空间; 没有可以移除的存证时返回 `StorageFull` 错误.
+		/// 除 `ClaimDeposit` 外, 每一级优先级还需要额外保留 `PriorityDeposit` 的押金.
+		/// 未启用 `ClaimEviction` 时优先级不被记录, 与 `create_claim` 完全相同.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::create_claim_with_priority(proof.len() as u32))]
+		pub fn create_claim_with_priority(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			algo: HashAlgo,
+			priority: u8,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+			Self::ensure_unclaimed(&namespace, &proof)?;
+
+			if !T::ClaimEviction::get() {
+				Self::do_create_claim(&sender, &sender, &namespace, proof.clone())?;
+			} else {
+				if ProofCount::<T>::get() >= T::MaxProofs::get() {
+					Self::evict_below(priority);
+				}
+
+				// 优先级越高保留的押金越多, 以免任何人都能免费使用最高的优先级挤掉其他存证
+				let deposit = T::ClaimDeposit::get().saturating_add(
+					T::PriorityDeposit::get().saturating_mul(BalanceOf::<T>::from(priority)),
+				);
+				Self::do_create_claim_with_deposit(
+					&sender,
+					&sender,
+					&namespace,
+					proof.clone(),
+					deposit,
+				)?;
+				Self::set_priority(&namespace, &proof, priority);
+			}
+			if algo != HashAlgo::default() {
+				ProofHashAlgo::<T>::insert(&namespace, &proof, algo);
+			}
+
+			Ok(().into())
+		}
+
+		/// 与 `create_claim` 相同, 但接受任意长度的存证.
+		/// 存证超出 `MaxBytesInHash` 时返回 `ProofTooLong` 错误, 而不是在解码时失败.
+		/// 权重与 `create_claim` 相同, 存证的长度按不超过 `MaxBytesInHash` 计算.
+		#[pallet::weight(T::WeightInfo::create_claim(
+			(proof.len() as u32).min(T::MaxBytesInHash::get())
+		))]
+		pub fn create_claim_unbounded(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: Vec<u8>,
+			algo: HashAlgo,
+		) -> DispatchResultWithPostInfo {
+			let proof = BoundedVec::<u8, T::MaxBytesInHash>::try_from(proof)
+				.map_err(|_| Error::<T>::ProofTooLong.with_weight(0))?;
+
+			Self::create_claim(origin, namespace, proof, algo)
+		}
+
+		/// 创建一个灵魂绑定的存证, 该存证只能被持有者撤销, 永远无法被转移.
+		#[pallet::weight(T::WeightInfo::create_soulbound_claim())]
+		pub fn create_soulbound_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+			Self::ensure_unclaimed(&namespace, &proof)?;
+
+			Self::do_create_claim(&sender, &sender, &namespace, proof.clone())?;
+			Soulbound::<T>::insert(&namespace, &proof, ());
+
+			// 发出一个灵魂绑定存证被创建的事件, 以便与普通存证区分
+			Self::deposit_event(Event::SoulboundClaimCreated(sender, namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 创建一个由调用者和 `co_owners` 共同持有的存证, 押金由调用者支付.
+		/// 每个持有者都可以转移自己的份额, 但撤销存证需要所有持有者通过 `approve_revoke` 同意.
+		/// 其他修改存证的操作仍然只能由第一个持有者(调用者)执行.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::create_shared_claim(co_owners.len() as u32))]
+		pub fn create_shared_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			co_owners: BoundedVec<T::AccountId, T::MaxOwners>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+			Self::ensure_unclaimed(&namespace, &proof)?;
+
+			// 调用者是第一个持有者, 持有者不能重复
+			let mut owners: BoundedVec<T::AccountId, T::MaxOwners> = Default::default();
+			for owner in sp_std::iter::once(&sender).chain(co_owners.iter()) {
+				ensure!(!owners.contains(owner), Error::<T>::DuplicateOwner);
+				owners.try_push(owner.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+			}
+
+			Self::do_create_claim(&sender, &sender, &namespace, proof.clone())?;
+
+			// 其他持有者的存证列表中同样记录该存证
+			for owner in co_owners.iter() {
+				ClaimsOf::<T>::try_mutate(owner, |claims| {
+					claims
+						.try_push((namespace.clone(), proof.clone()))
+						.map_err(|_| Error::<T>::ExceededMaxClaims)
+				})?;
+			}
+			SharedOwners::<T>::insert(&namespace, &proof, owners);
+
+			Self::deposit_event(Event::SharedClaimCreated(sender, namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 由 `author` 签名授权, 调用者代为提交并支付押金创建存证. 存证的持有者是 `author`,
+		/// 因此只有 `author` 可以撤销或转移该存证. `signature` 是 `author` 对
+		/// `signed_claim_payload` 的签名, 其中的 `nonce` 必须等于 `author` 当前的 `AccountNonces`,
+		/// 存证创建成功后递增, 因此每个签名只能使用一次.
+		///
+		/// 冻结、创建权限、身份和区块内的创建数量限制都针对 `author` 检查,
+		/// 代为提交的账户只需要未被冻结并能够支付押金.
+		#[pallet::weight(T::WeightInfo::create_claim_signed())]
+		pub fn create_claim_signed(
+			origin: OriginFor<T>,
+			author: T::AccountId,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			nonce: u64,
+			signature: T::AuthoritySignature,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_frozen(&sender)?;
+			Self::ensure_can_mutate(&author, ProofOp::Create)?;
+
+			// 已经使用过或尚未轮到的签名都被拒绝
+			ensure!(nonce == AccountNonces::<T>::get(&author), Error::<T>::BadNonce);
+
+			// 验证签名来自存证的作者, 签名针对提交的存证而不是其规范形式
+			let payload = Self::signed_claim_payload(&namespace, &proof, nonce);
+			ensure!(signature.verify(&payload[..], &author), Error::<T>::BadSignature);
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_unclaimed(&namespace, &proof)?;
+
+			Self::do_create_claim(&author, &sender, &namespace, proof)?;
+			AccountNonces::<T>::insert(&author, nonce.saturating_add(1));
+
+			Ok(().into())
+		}
+
+		/// 创建一个取代 `old_proof` 的新版本存证 `new_proof`, 例如合同修订后的新版本.
+		/// 只有 `old_proof` 的持有者可以调用. `old_proof` 不会被删除, 只是被标记为已被取代,
+		/// 每个存证最多被取代一次.
+		#[pallet::weight(T::WeightInfo::create_superseding_claim())]
+		pub fn create_superseding_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			new_proof: BoundedVec<u8, T::MaxBytesInHash>,
+			old_proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let new_proof = T::ProofNormalizer::normalize(new_proof);
+			let old_proof = T::ProofNormalizer::normalize(old_proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+
+			Self::ensure_owner(&namespace, &old_proof, &sender)?;
+			ensure!(
+				!SupersededBy::<T>::contains_key(&namespace, &old_proof),
+				Error::<T>::AlreadySuperseded.with_weight(Self::lookup_weight())
+			);
+			Self::ensure_unclaimed(&namespace, &new_proof)?;
+
+			Self::do_create_claim(&sender, &sender, &namespace, new_proof.clone())?;
+			Supersedes::<T>::insert(&namespace, &new_proof, &old_proof);
+			SupersededBy::<T>::insert(&namespace, &old_proof, &new_proof);
+
+			Self::deposit_event(Event::ClaimSuperseded(sender, namespace, new_proof, old_proof));
+			Ok(().into())
+		}
+
+		/// 由 `DepositWaiverOrigin` 为 `owner` 创建存证, 不保留押金. 存证记录的押金为 0,
+		/// `owner` 同时被记录为存证的创建者, 移除存证时不会解除保留任何金额.
+		/// 与 `create_claim` 一样发出 `ClaimCreated` 事件.
+		#[pallet::weight(T::WeightInfo::create_claim_no_deposit(proof.len() as u32))]
+		pub fn create_claim_no_deposit(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::DepositWaiverOrigin::ensure_origin(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&owner)?;
+			Self::ensure_unclaimed(&namespace, &proof)?;
+
+			Self::do_create_claim_with_deposit(&owner, &owner, &namespace, proof, Zero::zero())?;
+
+			Ok(().into())
+		}
+
+		/// 提议一个需要审批的存证, 例如需要组织内多人签字确认的高价值文件. `approvers` 中至少
+		/// `threshold` 个审批人通过 `approve_pending_claim` 批准之后存证才生效, 调用者成为持有者.
+		/// 提议时从调用者处保留 `ClaimDeposit`, 存证生效时转为存证的押金, 提议被撤回或过期后
+		/// 被移除时退还. 提议在 `PendingClaimTtl` 个区块后失效, 等待批准期间其他账户
+		/// 无法声明该存证.
+		#[pallet::weight(T::WeightInfo::propose_claim(approvers.len() as u32))]
+		pub fn propose_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			approvers: BoundedVec<T::AccountId, T::MaxApprovers>,
+			threshold: u32,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+			Self::ensure_valid_proof_length(&proof)?;
+			Self::ensure_unclaimed(&namespace, &proof)?;
+			ensure!(
+				!PendingClaims::<T>::contains_key(&namespace, &proof),
+				Error::<T>::ClaimPending
+			);
+
+			for (i, approver) in approvers.iter().enumerate() {
+				ensure!(!approvers[..i].contains(approver), Error::<T>::DuplicateApprover);
+			}
+			ensure!(
+				threshold > 0 && threshold as usize <= approvers.len(),
+				Error::<T>::InvalidThreshold
+			);
+
+			// 保留押金以免提议被用来免费占用存证
+			let deposit = T::ClaimDeposit::get();
+			T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+			PendingClaimDeposits::<T>::insert(&namespace, &proof, (sender.clone(), deposit));
+
+			let expires_at =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::PendingClaimTtl::get());
+			PendingClaims::<T>::insert(
+				&namespace,
+				&proof,
+				PendingClaim {
+					proposer: sender.clone(),
+					approvers,
+					approvals: Default::default(),
+					threshold,
+					expires_at,
+				},
+			);
+
+			Self::deposit_event(Event::ClaimProposed(
+				sender, namespace, proof, threshold, expires_at,
+			));
+			Ok(().into())
+		}
+
+		/// 由审批人批准等待批准的存证. 达到批准数量时存证生效, 退还提议时保留的押金后与
+		/// `create_claim` 一样从提议者处保留押金并发出 `ClaimCreated` 事件; 提议者无法支付押金时
+		/// 批准失败.
+		#[pallet::weight(T::WeightInfo::approve_pending_claim())]
+		#[transactional]
+		pub fn approve_pending_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+
+			let mut pending = PendingClaims::<T>::get(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NoSuchPendingClaim.with_weight(Self::lookup_weight()))?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= pending.expires_at,
+				Error::<T>::PendingClaimExpired.with_weight(Self::lookup_weight())
+			);
+			ensure!(
+				pending.approvers.contains(&sender),
+				Error::<T>::NotAnApprover.with_weight(Self::lookup_weight())
+			);
+			ensure!(
+				!pending.approvals.contains(&sender),
+				Error::<T>::AlreadyApproved.with_weight(Self::lookup_weight())
+			);
+			// 批准者都在审批人中且不重复, 因此不会超出上限
+			pending
+				.approvals
+				.try_push(sender.clone())
+				.map_err(|_| Error::<T>::NotAnApprover)?;
+
+			let approvals = pending.approvals.len() as u32;
+			Self::deposit_event(Event::PendingClaimApproved(
+				sender,
+				namespace.clone(),
+				proof.clone(),
+				approvals,
+			));
+
+			if approvals < pending.threshold {
+				PendingClaims::<T>::insert(&namespace, &proof, pending);
+				return Ok(().into())
+			}
+
+			// 达到批准数量, 存证生效
+			PendingClaims::<T>::remove(&namespace, &proof);
+			Self::release_pending_deposit(&namespace, &proof);
+			Self::ensure_not_frozen(&pending.proposer)?;
+			Self::ensure_can_create(&pending.proposer)?;
+			Self::do_create_claim(&pending.proposer, &pending.proposer, &namespace, proof)?;
+
+			Ok(().into())
+		}
+
+		/// 移除等待批准的存证并退还提议者的押金. 提议者可以随时撤回, 超过期限之后任何账户都可以
+		/// 移除.
+		#[pallet::weight(T::WeightInfo::remove_pending_claim())]
+		pub fn remove_pending_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			let pending = PendingClaims::<T>::get(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NoSuchPendingClaim.with_weight(Self::lookup_weight()))?;
+			ensure!(
+				sender == pending.proposer ||
+					<frame_system::Pallet<T>>::block_number() > pending.expires_at,
+				Error::<T>::PendingClaimNotExpired.with_weight(Self::lookup_weight())
+			);
+			PendingClaims::<T>::remove(&namespace, &proof);
+			Self::release_pending_deposit(&namespace, &proof);
+
+			Self::deposit_event(Event::PendingClaimRemoved(sender, namespace, proof));
+			Ok(().into())
+		}
+
+		/// 与 `create_claims` 相同, 但接受任意长度的存证. 超出 `MaxBytesInHash` 的存证会被跳过,
+		/// 跳过的数量通过 `OversizedProofsSkipped` 事件报告. 跳过的存证只收取查询所需的权重.
+		#[pallet::weight(T::WeightInfo::create_claims(proofs.len() as u32))]
+		pub fn create_claims_unbounded(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proofs: BoundedVec<Vec<u8>, T::MaxBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+
+			let total = proofs.len() as u32;
+			let mut created: u32 = 0;
+			let mut oversized: u32 = 0;
+			for proof in proofs {
+				match BoundedVec::<u8, T::MaxBytesInHash>::try_from(proof) {
+					// 跳过无法创建(例如已被声明)的存证, 每个被创建的存证都会发出一个事件
+					Ok(proof) =>
+						if Self::do_create_claim(
+							&sender,
+							&sender,
+							&namespace,
+							T::ProofNormalizer::normalize(proof),
+						)
+						.is_ok()
+						{
+							created += 1;
+						},
+					Err(_) => oversized += 1,
+				}
+			}
+
+			if oversized > 0 {
+				Self::deposit_event(Event::OversizedProofsSkipped(sender, namespace, oversized));
+			}
+			let skipped = total.saturating_sub(created);
+			Self::deposit_event(Event::BatchCompleted(created, skipped));
+
+			// 跳过的存证同样经过了检查, 只收取查询所需的权重
+			Ok(Some(
+				T::WeightInfo::create_claims(created)
+					.saturating_add(Self::lookup_weight().saturating_mul(skipped as Weight)),
+			)
+			.into())
+		}
+
+		/// 在同一个命名空间下批量创建存证. 已被声明的存证会被跳过而不是使整个批次失败,
+		/// 被跳过的存证只收取查询所需的权重, 其余的权重会退还给调用者.
+		#[pallet::weight(T::WeightInfo::create_claims(proofs.len() as u32))]
+		pub fn create_claims(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proofs: BoundedVec<BoundedVec<u8, T::MaxBytesInHash>, T::MaxBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_can_mutate(&sender, ProofOp::Create)?;
+
+			// 任意一个存证规范化后的长度不合法时整个批次失败,
+			// 这通常意味着调用者忘记了对内容进行哈希
+			let proofs: Vec<_> = proofs.into_iter().map(T::ProofNormalizer::normalize).collect();
+			for proof in proofs.iter() {
+				Self::ensure_valid_proof_length(proof)?;
+			}
+
+			let total = proofs.len() as u32;
+			let mut created: u32 = 0;
+			for proof in proofs {
+				// 跳过无法创建(例如已被声明)的存证, 每个被创建的存证都会发出一个事件
+				if Self::do_create_claim(&sender, &sender, &namespace, proof).is_ok() {
+					created += 1;
+				}
+			}
+			let skipped = total.saturating_sub(created);
+			Self::deposit_event(Event::BatchCompleted(created, skipped));
 
-     		// 发出一个存证所有权转移的事件
-            Self::deposit_event(Event::ClaimTransfered(sender, account, proof));
+			// 跳过的存证同样经过了检查, 只收取查询所需的权重
+			Ok(Some(
+				T::WeightInfo::create_claims(created)
+					.saturating_add(Self::lookup_weight().saturating_mul(skipped as Weight)),
+			)
+			.into())
+		}
 
-            Ok(())
-        }
+		#[pallet::weight(T::WeightInfo::transfer_claim())]
+		pub fn transfer_claim(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			// 如果 extrinsics 未进行名，此函数将返回一个错误
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			// 验证函数调用的发起者是否拥有存证的所有权, 共有存证的任意持有者都可以转移自己的份额.
+			Self::ensure_can_mutate(&sender, ProofOp::Transfer(&namespace, &proof))?;
 
-        #[pallet::weight(10_000)]
-        pub fn revoke_claim(
-            origin: OriginFor<T>,
-            proof: BoundedVec<u8, T::MaxBytesInHash>,
-        ) -> DispatchResult {
-          	// 检查 extrinsics 是否已签名，并找到签名者.
-            // 如果 extrinsics 未进行名，此函数将返回一个错误
-            // https://docs.substrate.io/v3/runtime/origins
-            let sender = ensure_signed(origin)?;
+			Self::do_transfer_claim(&namespace, &proof, &sender, &account, Default::default())?;
 
-			// 验证指定的存证是否被声明。
-            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+			Ok(().into())
+		}
 
-            // 获取创建者信息.
-            // Panic 条件: 无法设置一个 `None` 持有者, 因此总是需要使用 unwrap 包裹.
-            let (owner, _) = Proofs::<T>::get(&proof).expect("All proofs must have an owner!");
+		/// 与 `transfer_claim` 相同, 但在修改任何状态之前先检查接收者能否持有该存证, 并返回
+		/// 具体的错误: 接收者不能是调用者, 不能被冻结, 必须在存证的接收者名单中, 持有的存证数量
+		/// 不能达到上限; 启用 `RequireIdentity` 时接收者还必须已登记身份. `transfer_claim`
+		/// 不检查接收者的身份.
+		#[pallet::weight(T::WeightInfo::safe_transfer_claim())]
+		pub fn safe_transfer_claim(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
 
-            // 验证函数调用的发起者是否拥有存证的所有权.
-            ensure!(sender == owner, Error::<T>::NotProofOwner);
+			// 验证函数调用的发起者是否拥有存证的所有权, 共有存证的任意持有者都可以转移自己的份额.
+			Self::ensure_can_mutate(&sender, ProofOp::Transfer(&namespace, &proof))?;
 
-            // 从区块中移除存证声明.
-            Proofs::<T>::remove(&proof);
+			// 验证接收者能否持有该存证.
+			ensure!(sender != account, Error::<T>::CannotTransferToSelf);
+			Self::ensure_can_receive(&namespace, &proof, &account)?;
+			ensure!(
+				!T::RequireIdentity::get() || T::IdentityProvider::has_identity(&account),
+				Error::<T>::IdentityRequired
+			);
 
-       		// 发出一个存证被抹除的事件
-            Self::deposit_event(Event::ClaimRevoked(sender, proof));
-            Ok(())
-        }
-    }
+			Self::do_transfer_claim(&namespace, &proof, &sender, &account, Default::default())?;
+
+			Ok(().into())
+		}
+
+		/// 将存证转移给 `parent` 的第 `index` 个子账户, 子账户由 `subaccount_of` 计算,
+		/// 无需事先创建密钥. `ClaimTransferred` 事件中记录计算得到的子账户.
+		///
+		/// 子账户没有使用 `AccountIdConversion::into_sub_account` 计算: 那样得到的账户没有人能够
+		/// 代为签名. 这里沿用 `pallet_utility::Pallet::derivative_account_id` 的计算方式,
+		/// `parent` 可以通过 `pallet_utility::as_derivative` 以子账户的身份继续操作存证.
+		#[pallet::weight(T::WeightInfo::transfer_to_subaccount())]
+		pub fn transfer_to_subaccount(
+			origin: OriginFor<T>,
+			parent: T::AccountId,
+			index: u16,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			// 验证函数调用的发起者是否拥有存证的所有权, 共有存证的任意持有者都可以转移自己的份额.
+			Self::ensure_can_mutate(&sender, ProofOp::Transfer(&namespace, &proof))?;
+
+			let account = Self::subaccount_of(&parent, index);
+			Self::do_transfer_claim(&namespace, &proof, &sender, &account, Default::default())?;
+
+			Ok(().into())
+		}
+
+		/// 将调用者持有的多个存证一次性转移给 `dest`. 调用者不持有或无法转移的存证
+		/// (例如 `dest` 持有的存证已达到 `MaxClaimsPerAccount`)会被跳过而不是使整个批次失败,
+		/// 被跳过的存证只收取查询所需的权重, 其余的权重会退还给调用者.
+		#[pallet::weight(T::WeightInfo::transfer_claims(proofs.len() as u32))]
+		pub fn transfer_claims(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			namespace: NamespaceOf<T>,
+			proofs: BoundedVec<BoundedVec<u8, T::MaxBytesInHash>, T::MaxBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_can_mutate(&sender, ProofOp::Batch)?;
+
+			let total = proofs.len() as u32;
+			let mut transferred: u32 = 0;
+			for proof in proofs.into_iter().map(T::ProofNormalizer::normalize) {
+				// 每个被转移的存证都会发出一个 `ClaimTransferred` 事件
+				if Self::is_owner(&namespace, &proof, &sender) &&
+					Self::do_transfer_claim(
+						&namespace,
+						&proof,
+						&sender,
+						&dest,
+						Default::default(),
+					)
+					.is_ok()
+				{
+					transferred += 1;
+				}
+			}
+			let skipped = total.saturating_sub(transferred);
+			Self::deposit_event(Event::BatchCompleted(transferred, skipped));
+
+			// 跳过的存证同样经过了检查, 只收取查询所需的权重
+			Ok(Some(
+				T::WeightInfo::transfer_claims(transferred)
+					.saturating_add(Self::lookup_weight().saturating_mul(skipped as Weight)),
+			)
+			.into())
+		}
+
+		/// 与 `transfer_claim` 相同, 但在 `ClaimTransferred` 事件中附加一段备注,
+		/// 例如交付契约时给买方的说明. 备注只记录在事件中, 不写入存储.
+		#[pallet::weight(T::WeightInfo::transfer_claim_with_memo(memo.len() as u32))]
+		pub fn transfer_claim_with_memo(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			memo: BoundedVec<u8, T::MaxMemoLen>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			// 验证函数调用的发起者是否拥有存证的所有权,
+			// 共有存证的任意持有者都可以转移自己的份额.
+			Self::ensure_can_mutate(&sender, ProofOp::Transfer(&namespace, &proof))?;
+
+			Self::do_transfer_claim(&namespace, &proof, &sender, &account, memo)?;
+
+			Ok(().into())
+		}
+
+		/// 转移存证并为新的持有者设置描述信息, 两者在同一个事务中完成, 任何一步失败时都不会转移.
+		/// 只有 `Proofs` 中记录的持有者可以调用, 共有存证的其他持有者无法同时设置描述信息.
+		#[pallet::weight(T::WeightInfo::transfer_and_set_metadata())]
+		#[transactional]
+		pub fn transfer_and_set_metadata(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			metadata: BoundedVec<u8, T::MaxMetadataLength>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_can_mutate(&sender, ProofOp::Transfer(&namespace, &proof))?;
+			let claim = Self::claim_of(&namespace, &proof)?;
+			ensure!(
+				sender == claim.owner,
+				Error::<T>::NotProofOwner.with_weight(Self::lookup_weight())
+			);
+
+			Self::do_transfer_claim(&namespace, &proof, &sender, &account, Default::default())?;
+			let version = Self::do_set_metadata(&namespace, &proof, metadata);
+
+			// 发出一个存证描述信息被设置的事件
+			Self::deposit_event(Event::MetadataSet(sender, namespace, proof, version));
+			Ok(().into())
+		}
+
+		/// 由存证的持有者或其撤销代理撤销存证. 存证会被记录在 `RevokedProofs` 中,
+		/// 在被 `burn_claim` 彻底删除之前同一个存证无法再次被声明. 撤销记录同样占用存储,
+		/// 因此存证的押金保留到撤销记录被删除时才退还给创建者.
+		#[pallet::weight(T::WeightInfo::revoke_claim())]
+		pub fn revoke_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			// 如果 extrinsics 未进行名，此函数将返回一个错误
+			// https://docs.substrate.io/v3/runtime/origins
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+
+			// 验证函数调用的发起者是存证的持有者或持有者的撤销代理.
+			Self::ensure_can_mutate(&sender, ProofOp::Revoke(&namespace, &proof))?;
+			let claim = Self::claim_of(&namespace, &proof)?;
+			Self::ensure_not_locked(&namespace, &proof)?;
+			Self::ensure_revoke_approved(&namespace, &proof)?;
+
+			// 从区块中移除存证声明及其关联数据, 押金在撤销记录被删除时才退还给创建者.
+			Self::take_claim(&namespace, &proof);
+			Self::note_revoked(&claim.owner);
+			T::OnClaimHandler::on_revoked(&sender, &namespace, &proof);
+
+			// 记录被撤销的存证, 防止同一个存证被再次声明
+			let now = <frame_system::Pallet<T>>::block_number();
+			RevokedProofs::<T>::insert(&namespace, &proof, (sender.clone(), now));
+			if !claim.deposit.is_zero() {
+				TombstoneDeposits::<T>::insert(
+					&namespace,
+					&proof,
+					(claim.creator.clone(), claim.deposit),
+				);
+			}
+
+			// 发出一个存证被抹除的事件
+			Self::deposit_event(Event::ClaimRevoked(sender, namespace, proof, claim.creator, now));
+			Ok(().into())
+		}
+
+		/// 与 `transfer_claim` 相同, 但通过存证的编号指定存证.
+		#[pallet::weight(T::WeightInfo::transfer_claim().saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn transfer_claim_by_id(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			id: u64,
+		) -> DispatchResultWithPostInfo {
+			let (namespace, proof) = Self::proof_of_id(id)?;
+			Self::transfer_claim(origin, account, namespace, proof)
+		}
+
+		/// 与 `revoke_claim` 相同, 但通过存证的编号指定存证.
+		#[pallet::weight(T::WeightInfo::revoke_claim().saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn revoke_claim_by_id(origin: OriginFor<T>, id: u64) -> DispatchResultWithPostInfo {
+			let (namespace, proof) = Self::proof_of_id(id)?;
+			Self::revoke_claim(origin, namespace, proof)
+		}
+
+		/// 由共有存证的持有者同意撤销存证. 所有持有者都同意之后, 任意一个持有者都可以通过
+		/// `revoke_claim` 撤销存证. 持有者转移自己的份额时, 其同意随之失效.
+		#[pallet::weight(T::WeightInfo::approve_revoke())]
+		pub fn approve_revoke(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			let owners = SharedOwners::<T>::get(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NotAnOwner.with_weight(Self::lookup_weight()))?;
+			ensure!(
+				owners.contains(&sender),
+				Error::<T>::NotAnOwner.with_weight(Self::lookup_weight())
+			);
+
+			RevokeApprovals::<T>::try_mutate(&namespace, &proof, |approvals| {
+				if !approvals.contains(&sender) {
+					approvals.try_push(sender.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::RevokeApproved(sender, namespace, proof));
+			Ok(().into())
+		}
+
+		/// 彻底删除存证, 释放存储空间并将押金退还给创建者, 之后同一个存证可以被再次声明.
+		/// 有效的存证只能由持有者删除, 已撤销的存证只能由撤销者删除.
+		#[pallet::weight(T::WeightInfo::burn_claim())]
+		pub fn burn_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			if let Some((revoker, _)) = RevokedProofs::<T>::get(&namespace, &proof) {
+				// 只有撤销者才能删除已撤销存证的记录.
+				ensure!(sender == revoker, Error::<T>::NotProofOwner);
+				RevokedProofs::<T>::remove(&namespace, &proof);
+				if let Some((creator, deposit)) = TombstoneDeposits::<T>::take(&namespace, &proof) {
+					Self::unreserve_deposit(&creator, &namespace, &proof, deposit);
+				}
+			} else {
+				// 验证函数调用的发起者是否拥有存证的所有权.
+				Self::ensure_owner(&namespace, &proof, &sender)?;
+				Self::ensure_not_locked(&namespace, &proof)?;
+				Self::ensure_revoke_approved(&namespace, &proof)?;
+
+				// 从区块中移除存证声明及其关联数据, 并将押金退还给创建者.
+				Self::remove_claim(&namespace, &proof);
+				T::OnClaimHandler::on_revoked(&sender, &namespace, &proof);
+			}
+
+			// 发出一个存证被彻底删除的事件
+			Self::deposit_event(Event::ClaimBurned(sender, namespace, proof));
+			Ok(().into())
+		}
+
+		/// 由管理员强制撤销存证, 无需持有者同意, 被锁定的存证也可以被撤销.
+		/// 押金仍然退还给创建者.
+		#[pallet::weight(T::WeightInfo::force_revoke_claim())]
+		pub fn force_revoke_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+
+			// 从区块中移除存证声明及其关联数据, 存证不存在时返回 `NoSuchProof` 错误.
+			let claim = Self::remove_claim(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NoSuchProof.with_weight(Self::lookup_weight()))?;
+			Self::note_revoked(&claim.owner);
+			T::OnClaimHandler::on_revoked(&claim.owner, &namespace, &proof);
+
+			// 发出一个存证被强制撤销的事件
+			Self::deposit_event(Event::ClaimForceRevoked(namespace, proof));
+			Ok(().into())
+		}
+
+		/// 通过集体投票撤销存证, 用于由治理决定的内容审核. 被锁定的存证也可以被撤销,
+		/// 押金仍然退还给创建者.
+		#[pallet::weight(T::WeightInfo::council_revoke_claim())]
+		pub fn council_revoke_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+
+			// 从区块中移除存证声明及其关联数据, 存证不存在时返回 `NoSuchProof` 错误.
+			let claim = Self::remove_claim(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NoSuchProof.with_weight(Self::lookup_weight()))?;
+			Self::note_revoked(&claim.owner);
+			T::OnClaimHandler::on_revoked(&claim.owner, &namespace, &proof);
+
+			Self::deposit_event(Event::ClaimRevokedByCouncil(namespace, proof));
+			Ok(().into())
+		}
+
+		/// 对存证提出质疑, 任何账户都可以调用, 并保留 `ChallengeBond` 作为保证金.
+		/// 质疑由管理员通过 `resolve_challenge` 处理, 而不是立即撤销存证.
+		#[pallet::weight(T::WeightInfo::challenge_claim())]
+		pub fn challenge_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			reason: BoundedVec<u8, T::MaxMetadataLength>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::claim_of(&namespace, &proof)?;
+			ensure!(
+				!Challenges::<T>::contains_key(&namespace, &proof),
+				Error::<T>::ChallengeInProgress.with_weight(Self::lookup_weight())
+			);
+
+			let bond = T::ChallengeBond::get();
+			T::Currency::reserve(&sender, bond).map_err(|_| Error::<T>::InsufficientBond)?;
+
+			let resolve_by =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::ChallengePeriod::get());
+			Challenges::<T>::insert(
+				&namespace,
+				&proof,
+				Challenge { challenger: sender.clone(), bond, reason, resolve_by },
+			);
+
+			Self::deposit_event(Event::ClaimChallenged(sender, namespace, proof, resolve_by));
+			Ok(().into())
+		}
+
+		/// 由管理员处理存证的质疑. `uphold` 为 `true` 时撤销存证并将押金退还给创建者,
+		/// 否则驳回质疑. 两种情况下质疑保证金都退还给质疑者.
+		#[pallet::weight(T::WeightInfo::resolve_challenge())]
+		pub fn resolve_challenge(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			uphold: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+
+			let challenge = Challenges::<T>::take(&namespace, &proof).ok_or_else(|| {
+				Error::<T>::NoSuchChallenge.with_weight(T::DbWeight::get().reads(1))
+			})?;
+			T::Currency::unreserve(&challenge.challenger, challenge.bond);
+
+			if uphold {
+				if let Some(claim) = Self::remove_claim(&namespace, &proof) {
+					Self::note_revoked(&claim.owner);
+					T::OnClaimHandler::on_revoked(&claim.owner, &namespace, &proof);
+					Self::deposit_event(Event::ClaimForceRevoked(namespace.clone(), proof.clone()));
+				}
+			}
+
+			Self::deposit_event(Event::ChallengeResolved(namespace, proof, uphold));
+			Ok(().into())
+		}
+
+		/// 由管理员强制撤销存证并罚没押金, 用于处理滥用的存证.
+		/// 押金先被解除保留, 再从创建者账户中罚没, 罚没的金额交给 `OnSlash` 处理.
+		#[pallet::weight(T::WeightInfo::force_revoke_and_slash())]
+		pub fn force_revoke_and_slash(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+
+			// 押金仍然被保留, 直接从保留的余额中罚没
+			let claim = Self::take_claim(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NoSuchProof.with_weight(Self::lookup_weight()))?;
+			Self::note_revoked(&claim.owner);
+			T::OnClaimHandler::on_revoked(&claim.owner, &namespace, &proof);
+
+			let amount = Self::slash_deposit(&claim.creator, claim.deposit);
+
+			Self::deposit_event(Event::ClaimForceRevoked(namespace, proof));
+			Self::deposit_event(Event::ClaimSlashed(claim.creator, amount));
+			Ok(().into())
+		}
+
+		/// 由管理员批量强制撤销同一命名空间中的多个存证, 用于处理影响大量存证的安全事件.
+		/// `slash` 为 `true` 时罚没押金并交给 `OnSlash` 处理, 否则将押金退还给创建者.
+		/// 不存在的存证会被跳过并计入 `BatchCompleted` 事件, 只对实际撤销的存证收取完整的权重.
+		#[pallet::weight(T::WeightInfo::force_revoke_claims(proofs.len() as u32))]
+		pub fn force_revoke_claims(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proofs: BoundedVec<BoundedVec<u8, T::MaxBytesInHash>, T::MaxBatchSize>,
+			slash: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let total = proofs.len() as u32;
+			let mut revoked: u32 = 0;
+			for proof in proofs.into_iter().map(T::ProofNormalizer::normalize) {
+				// 罚没时押金直接从保留的余额中扣除, 不会先被退还
+				let claim = match Self::take_claim(&namespace, &proof) {
+					Some(claim) => claim,
+					None => continue,
+				};
+				revoked += 1;
+				Self::note_revoked(&claim.owner);
+				T::OnClaimHandler::on_revoked(&claim.owner, &namespace, &proof);
+
+				if slash {
+					let amount = Self::slash_deposit(&claim.creator, claim.deposit);
+					Self::deposit_event(Event::ClaimForceRevoked(namespace.clone(), proof));
+					Self::deposit_event(Event::ClaimSlashed(claim.creator, amount));
+				} else {
+					Self::unreserve_deposit(&claim.creator, &namespace, &proof, claim.deposit);
+					Self::deposit_event(Event::ClaimForceRevoked(namespace.clone(), proof));
+				}
+			}
+			let skipped = total.saturating_sub(revoked);
+			Self::deposit_event(Event::BatchCompleted(revoked, skipped));
+
+			// 跳过的存证只收取查询所需的权重
+			Ok(Some(
+				T::WeightInfo::force_revoke_claims(revoked)
+					.saturating_add(Self::lookup_weight().saturating_mul(skipped as Weight)),
+			)
+			.into())
+		}
+
+		/// 由管理员暂停或恢复 pallet, 用于在紧急情况下冻结所有存证的修改操作.
+		#[pallet::weight(T::WeightInfo::set_paused())]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Paused::<T>::put(paused);
+
+			// 发出一个暂停状态变化的事件
+			Self::deposit_event(Event::PausedStateChanged(paused));
+			Ok(())
+		}
+
+		/// 由管理员将账户加入存证创建者名单.
+		#[pallet::weight(T::WeightInfo::add_creator())]
+		pub fn add_creator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			AllowedCreators::<T>::insert(&who, ());
+
+			// 发出一个创建者被加入名单的事件
+			Self::deposit_event(Event::CreatorAdded(who));
+			Ok(())
+		}
+
+		/// 由管理员将账户移出存证创建者名单. 该账户已创建的存证不受影响.
+		#[pallet::weight(T::WeightInfo::remove_creator())]
+		pub fn remove_creator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			AllowedCreators::<T>::remove(&who);
+
+			// 发出一个创建者被移出名单的事件
+			Self::deposit_event(Event::CreatorRemoved(who));
+			Ok(())
+		}
+
+		/// 由管理员冻结账户. 被冻结的账户无法创建、转移或撤销存证, 也无法接收存证,
+		/// 修改存证状态的其他签名调用(授权、出售、描述信息、标签、续期等)同样会被拒绝.
+		/// 清理过期存证和锁定自己的转移不受影响.
+		#[pallet::weight(T::WeightInfo::freeze_account())]
+		pub fn freeze_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			FrozenAccounts::<T>::insert(&who, ());
+
+			// 发出一个账户被冻结的事件
+			Self::deposit_event(Event::AccountFrozen(who));
+			Ok(())
+		}
+
+		/// 由管理员解冻账户.
+		#[pallet::weight(T::WeightInfo::unfreeze_account())]
+		pub fn unfreeze_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			FrozenAccounts::<T>::remove(&who);
+
+			// 发出一个账户被解冻的事件
+			Self::deposit_event(Event::AccountUnfrozen(who));
+			Ok(())
+		}
+
+		/// 授权 `delegate` 撤销调用者持有的任意存证.
+		#[pallet::weight(T::WeightInfo::add_revoke_delegate())]
+		pub fn add_revoke_delegate(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			RevokeDelegates::<T>::insert(&sender, &delegate, ());
+
+			// 发出一个撤销代理被添加的事件
+			Self::deposit_event(Event::RevokeDelegateAdded(sender, delegate));
+			Ok(().into())
+		}
+
+		/// 取消 `delegate` 撤销调用者存证的授权.
+		#[pallet::weight(T::WeightInfo::remove_revoke_delegate())]
+		pub fn remove_revoke_delegate(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			RevokeDelegates::<T>::remove(&sender, &delegate);
+
+			// 发出一个撤销代理被移除的事件
+			Self::deposit_event(Event::RevokeDelegateRemoved(sender, delegate));
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			metadata: BoundedVec<u8, T::MaxMetadataLength>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者或持有者授权的编辑者才能设置描述信息.
+			let claim = Self::claim_of(&namespace, &proof)?;
+			ensure!(
+				sender == claim.owner ||
+					MetadataEditors::<T>::get(&namespace, &proof).contains(&sender),
+				Error::<T>::NotProofOwner.with_weight(Self::lookup_weight())
+			);
+
+			let version = Self::do_set_metadata(&namespace, &proof, metadata);
+
+			// 发出一个存证描述信息被设置的事件
+			Self::deposit_event(Event::MetadataSet(sender, namespace, proof, version));
+
+			Ok(().into())
+		}
+
+		/// 授权 `editor` 设置调用者持有的存证的描述信息, 编辑者无法转移或撤销存证.
+		#[pallet::weight(T::WeightInfo::add_metadata_editor())]
+		pub fn add_metadata_editor(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			editor: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			MetadataEditors::<T>::try_mutate(&namespace, &proof, |editors| {
+				if !editors.contains(&editor) {
+					editors.try_push(editor.clone()).map_err(|_| Error::<T>::TooManyEditors)?;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 发出一个描述信息编辑者被添加的事件
+			Self::deposit_event(Event::MetadataEditorAdded(sender, namespace, proof, editor));
+			Ok(().into())
+		}
+
+		/// 取消 `editor` 设置调用者持有的存证的描述信息的授权.
+		#[pallet::weight(T::WeightInfo::remove_metadata_editor())]
+		pub fn remove_metadata_editor(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			editor: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			MetadataEditors::<T>::try_mutate_exists(&namespace, &proof, |maybe_editors| {
+				let editors = maybe_editors.as_mut().ok_or(Error::<T>::NotAnEditor)?;
+				let index =
+					editors.iter().position(|who| *who == editor).ok_or(Error::<T>::NotAnEditor)?;
+				editors.remove(index);
+				if editors.is_empty() {
+					*maybe_editors = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 发出一个描述信息编辑者被移除的事件
+			Self::deposit_event(Event::MetadataEditorRemoved(sender, namespace, proof, editor));
+			Ok(().into())
+		}
+
+		/// 将 `recipient` 加入调用者持有的存证的接收者名单.
+		/// 名单不为空时存证只能被转移给名单中的账户.
+		#[pallet::weight(T::WeightInfo::allow_recipient())]
+		pub fn allow_recipient(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			recipient: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			TransferAllowlist::<T>::try_mutate(&namespace, &proof, |allowlist| {
+				if !allowlist.contains(&recipient) {
+					allowlist
+						.try_push(recipient.clone())
+						.map_err(|_| Error::<T>::TooManyRecipients)?;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 发出一个接收者被加入名单的事件
+			Self::deposit_event(Event::RecipientAllowed(sender, namespace, proof, recipient));
+			Ok(().into())
+		}
+
+		/// 将 `recipient` 移出调用者持有的存证的接收者名单. 名单被清空后存证可以被转移给任何账户.
+		#[pallet::weight(T::WeightInfo::disallow_recipient())]
+		pub fn disallow_recipient(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			recipient: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			TransferAllowlist::<T>::try_mutate_exists(&namespace, &proof, |maybe_allowlist| {
+				let allowlist = maybe_allowlist.as_mut().ok_or(Error::<T>::NotAllowedRecipient)?;
+				let index = allowlist
+					.iter()
+					.position(|who| *who == recipient)
+					.ok_or(Error::<T>::NotAllowedRecipient)?;
+				allowlist.remove(index);
+				if allowlist.is_empty() {
+					*maybe_allowlist = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 发出一个接收者被移出名单的事件
+			Self::deposit_event(Event::RecipientDisallowed(sender, namespace, proof, recipient));
+			Ok(().into())
+		}
+
+		/// 设置(或覆盖)存证内容的 MIME 类型. 传入空的内容类型会清除已设置的内容类型.
+		#[pallet::weight(T::WeightInfo::set_content_type())]
+		pub fn set_content_type(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			content_type: BoundedVec<u8, T::MaxMimeLen>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能设置内容类型.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			if content_type.is_empty() {
+				ContentTypes::<T>::remove(&namespace, &proof);
+			} else {
+				ContentTypes::<T>::insert(&namespace, &proof, &content_type);
+			}
+
+			Self::deposit_event(Event::ContentTypeSet(sender, namespace, proof, content_type));
+
+			Ok(().into())
+		}
+
+		/// 设置(或覆盖)存证的属性 `key`. 新的属性会使存证的属性数量加一,
+		/// 数量已达到 `MaxAttributesPerProof` 时返回 `TooManyAttributes` 错误.
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			key: AttributeKeyOf<T>,
+			value: AttributeValueOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能设置属性.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			let attribute_key = (namespace.clone(), proof.clone());
+			if !Attributes::<T>::contains_key(&attribute_key, &key) {
+				AttributeCount::<T>::try_mutate(&namespace, &proof, |count| {
+					ensure!(
+						*count < T::MaxAttributesPerProof::get(),
+						Error::<T>::TooManyAttributes
+					);
+					*count += 1;
+					Ok::<_, Error<T>>(())
+				})?;
+			}
+			Attributes::<T>::insert(&attribute_key, &key, &value);
+
+			Self::deposit_event(Event::AttributeSet(sender, namespace, proof, key, value));
+			Ok(().into())
+		}
+
+		/// 清除存证的属性 `key`, 存证没有设置该属性时返回 `NoSuchAttribute` 错误.
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			key: AttributeKeyOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能清除属性.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			Attributes::<T>::take((namespace.clone(), proof.clone()), &key)
+				.ok_or(Error::<T>::NoSuchAttribute)?;
+			AttributeCount::<T>::mutate(&namespace, &proof, |count| {
+				*count = count.saturating_sub(1)
+			});
+
+			Self::deposit_event(Event::AttributeCleared(sender, namespace, proof, key));
+			Ok(().into())
+		}
+
+		/// 设置(或覆盖)存证的标签, 重复的标签只保留一个. 传入空的列表会清除所有标签.
+		#[pallet::weight(T::WeightInfo::set_tags(T::MaxTagsPerProof::get()))]
+		pub fn set_tags(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			tags: BoundedVec<TagOf<T>, T::MaxTagsPerProof>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能设置标签.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			let mut tags = tags.into_inner();
+			tags.sort();
+			tags.dedup();
+			let tags = BoundedVec::<TagOf<T>, T::MaxTagsPerProof>::try_from(tags)
+				.map_err(|_| Error::<T>::TooManyTags)?;
+
+			// 先移除旧标签的索引, 再为新标签建立索引
+			let old_tags = Self::clear_tags(&namespace, &proof);
+			for tag in tags.iter() {
+				TagIndex::<T>::insert((namespace.clone(), tag.clone()), &proof, ());
+			}
+			let touched = old_tags.max(tags.len() as u32);
+			if !tags.is_empty() {
+				ProofTags::<T>::insert(&namespace, &proof, tags);
+			}
+
+			// 发出一个存证标签被设置的事件
+			Self::deposit_event(Event::TagsSet(sender, namespace, proof));
+
+			// 只对实际修改的标签收取权重
+			Ok(Some(T::WeightInfo::set_tags(touched)).into())
+		}
+
+		/// 在存证过期之前将其有效期从当前区块起重新计算. 持有者需要支付 `RenewalFee`,
+		/// 无法支付时返回 `InsufficientBalance` 错误.
+		#[pallet::weight(T::WeightInfo::renew_claim())]
+		pub fn renew_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能延长有效期, 不是持有者时只收取查询所需的权重.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+			Self::do_renew_claim(&sender, &namespace, &proof, None)?;
+
+			Ok(().into())
+		}
+
+		/// 将调用者持有的多个存证的有效期各自延长 `additional_blocks` 个区块, 每个存证收取一次
+		/// `RenewalFee`. `additional_blocks` 不能为 0, 也不能超过 `ClaimTtl`. 调用者不持有、
+		/// 不会过期、已经过期或无法支付费用的存证会被跳过, 跳过的存证只收取查询所需的权重.
+		#[pallet::weight(T::WeightInfo::renew_claims(proofs.len() as u32))]
+		pub fn renew_claims(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proofs: BoundedVec<BoundedVec<u8, T::MaxBytesInHash>, T::MaxBatchSize>,
+			additional_blocks: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+			ensure!(
+				!additional_blocks.is_zero() && additional_blocks <= T::ClaimTtl::get(),
+				Error::<T>::InvalidRenewalPeriod
+			);
+
+			let total = proofs.len() as u32;
+			let mut renewed: u32 = 0;
+			for proof in proofs.into_iter().map(T::ProofNormalizer::normalize) {
+				if Self::do_renew_claim(&sender, &namespace, &proof, Some(additional_blocks))
+					.is_ok()
+				{
+					renewed += 1;
+				}
+			}
+			let skipped = total.saturating_sub(renewed);
+			Self::deposit_event(Event::BatchCompleted(renewed, skipped));
+
+			// 跳过的存证同样经过了检查, 只收取查询所需的权重
+			Ok(Some(
+				T::WeightInfo::renew_claims(renewed)
+					.saturating_add(Self::lookup_weight().saturating_mul(skipped as Weight)),
+			)
+			.into())
+		}
+
+		/// 清理最多 `limit` 个已经过期的存证, 任何人都可以调用. 用于 `AutoExpire` 为 `false` 的链,
+		/// 由调用者决定清理过期存证的时机, 与 `on_idle` 的清理共享 `ExpiryCursor`.
+		/// 每个被清理的存证都会发出一个 `ClaimExpired` 事件, 没有需要清理的存证时发出
+		/// `NothingToClean` 事件. 只对实际清理的存证和检查的区块收取权重.
+		///
+		/// 单次最多清理 `MaxExpiredPerBlock` 个存证, 最多检查 `MAX_EXPIRY_BLOCKS_PER_CLEAN` 个区块.
+		#[pallet::weight(
+			T::WeightInfo::clean_expired(limit.min(T::MaxExpiredPerBlock::get()))
+				.saturating_add(T::DbWeight::get().reads(MAX_EXPIRY_BLOCKS_PER_CLEAN as Weight))
+		)]
+		pub fn clean_expired(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused()?;
+
+			let (cleaned, visited) = Self::sweep_expired(
+				limit.min(T::MaxExpiredPerBlock::get()),
+				MAX_EXPIRY_BLOCKS_PER_CLEAN,
+				Weight::MAX,
+			);
+
+			if cleaned == 0 {
+				Self::deposit_event(Event::NothingToClean(sender));
+			}
+
+			Ok(Some(
+				T::WeightInfo::clean_expired(cleaned)
+					.saturating_add(T::DbWeight::get().reads(visited as Weight)),
+			)
+			.into())
+		}
+
+		/// 由存证的持有者或管理员固定存证. 被固定的存证在过期时不会被清理,
+		/// 其过期区块在取消固定之前不起作用.
+		#[pallet::weight(T::WeightInfo::pin_claim())]
+		pub fn pin_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_owner_or_admin(origin, &namespace, &proof)?;
+			ensure!(!Pinned::<T>::contains_key(&namespace, &proof), Error::<T>::AlreadyPinned);
+
+			Pinned::<T>::insert(&namespace, &proof, ());
+
+			// 发出一个存证被固定的事件
+			Self::deposit_event(Event::ClaimPinned(namespace, proof));
+			Ok(().into())
+		}
+
+		/// 由存证的持有者或管理员取消固定存证. 存证在固定期间已经过期时会在下一个区块过期.
+		#[pallet::weight(T::WeightInfo::unpin_claim())]
+		#[transactional]
+		pub fn unpin_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_owner_or_admin(origin, &namespace, &proof)?;
+			Pinned::<T>::take(&namespace, &proof).ok_or(Error::<T>::NotPinned)?;
+
+			// 固定期间已经从过期队列中取出的存证重新加入下一个区块的过期队列
+			let now = <frame_system::Pallet<T>>::block_number();
+			if let Some(expires_at) = ProofExpiry::<T>::get(&namespace, &proof) {
+				if expires_at <= now {
+					let next = now.saturating_add(One::one());
+					Self::enqueue_expiry(next, &namespace, &proof)?;
+					ProofExpiry::<T>::insert(&namespace, &proof, next);
+				}
+			}
+
+			// 发出一个存证被取消固定的事件
+			Self::deposit_event(Event::ClaimUnpinned(namespace, proof));
+			Ok(().into())
+		}
+
+		/// 授权 `spender` 代替持有者转移存证, 会覆盖之前的授权.
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			spender: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能授权.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			Approvals::<T>::insert(&namespace, &proof, &spender);
+
+			// 发出一个存证转移授权被设置的事件
+			Self::deposit_event(Event::ApprovalSet(sender, spender, namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 由被授权的账户(或持有者本人)将存证转移给 `dest`, 成功后授权被自动清除.
+		///
+		/// 调用者不是持有者时: 存证已授权给其他账户则返回 `NotApproved`, 存证没有任何授权则
+		/// 只能由持有者转移, 返回 `NotProofOwner`.
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			dest: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+
+			// 调用者必须是存证的持有者或被授权的账户.
+			Self::ensure_can_mutate(&sender, ProofOp::TransferFrom(&namespace, &proof))?;
+			let claim = Self::claim_of(&namespace, &proof)?;
+
+			Self::do_transfer_claim(&namespace, &proof, &claim.owner, &dest, Default::default())?;
+
+			Ok(().into())
+		}
+
+		/// 向 `dest` 发起一个存证转移, 在 `dest` 调用 `accept_transfer` 之前所有权不会改变.
+		/// 会覆盖之前尚未被接收的转移. `TransferBond` 不为 0 时从调用者处保留保证金,
+		/// 转移被接收或撤回后退还, 被覆盖的转移的保证金立即退还.
+		#[pallet::weight(T::WeightInfo::offer_transfer())]
+		pub fn offer_transfer(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能发起转移.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+			ensure!(sender != dest, Error::<T>::CannotTransferToSelf);
+
+			// 灵魂绑定的存证无法被转移.
+			ensure!(
+				!Soulbound::<T>::contains_key(&namespace, &proof),
+				Error::<T>::ClaimNotTransferable
+			);
+
+			// 先保留新的保证金, 保留失败时不修改任何状态
+			let bond = T::TransferBond::get();
+			if !bond.is_zero() {
+				T::Currency::reserve(&sender, bond).map_err(|_| Error::<T>::InsufficientBond)?;
+			}
+			Self::release_transfer_bond(&namespace, &proof);
+			if !bond.is_zero() {
+				TransferBonds::<T>::insert(&namespace, &proof, (sender.clone(), bond));
+				Self::deposit_event(Event::TransferBondReserved(
+					sender.clone(),
+					namespace.clone(),
+					proof.clone(),
+					bond,
+				));
+			}
+
+			PendingTransfers::<T>::insert(&namespace, &proof, &dest);
+
+			// 发出一个存证转移被发起的事件
+			Self::deposit_event(Event::TransferOffered(sender, dest, namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 由待接收转移的接收者调用, 完成存证所有权的转移.
+		#[pallet::weight(T::WeightInfo::accept_transfer())]
+		pub fn accept_transfer(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+
+			let dest = PendingTransfers::<T>::get(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NoPendingTransfer.with_weight(Self::lookup_weight()))?;
+			ensure!(
+				sender == dest,
+				Error::<T>::NotTransferRecipient.with_weight(Self::lookup_weight())
+			);
+
+			let claim = Self::claim_of(&namespace, &proof)?;
+
+			// 转移所有权时会同时清除待接收的转移
+			Self::do_transfer_claim(&namespace, &proof, &claim.owner, &sender, Default::default())?;
+
+			// 发出一个存证转移被接受的事件
+			Self::deposit_event(Event::TransferAccepted(claim.owner, sender, namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 由存证的持有者撤回尚未被接收的转移.
+		#[pallet::weight(T::WeightInfo::cancel_transfer())]
+		pub fn cancel_transfer(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能撤回转移.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			PendingTransfers::<T>::take(&namespace, &proof).ok_or(Error::<T>::NoPendingTransfer)?;
+			Self::release_transfer_bond(&namespace, &proof);
+
+			// 发出一个存证转移被撤回的事件
+			Self::deposit_event(Event::TransferCancelled(sender, namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 由存证的持有者以 `price` 的价格出售存证, 覆盖之前的价格. 任何账户都可以通过
+		/// `buy_claim` 购买. 存证在售出之前被转移或移除时, 出售自动取消.
+		#[pallet::weight(T::WeightInfo::list_for_sale())]
+		pub fn list_for_sale(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能出售存证, 灵魂绑定的存证无法出售.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+			ensure!(
+				!Soulbound::<T>::contains_key(&namespace, &proof),
+				Error::<T>::ClaimNotTransferable
+			);
+
+			Listings::<T>::insert(&namespace, &proof, (sender.clone(), price));
+
+			Self::deposit_event(Event::ClaimListed(sender, namespace, proof, price));
+			Ok(().into())
+		}
+
+		/// 以出售的价格购买存证, 存证的所有权转移给买方. 价格中的 `RoyaltyBps` 部分作为版税
+		/// 支付给存证的创建者, 其余部分支付给卖方. 卖方就是创建者时全部价格一次性支付给卖方.
+		/// 当前价格高于 `max_price` 时拒绝购买, 以免卖方在交易执行前抬高价格.
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::buy_claim())]
+		pub fn buy_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			max_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+
+			let (seller, price) = Listings::<T>::get(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NotForSale.with_weight(T::DbWeight::get().reads(2)))?;
+			ensure!(
+				price <= max_price,
+				Error::<T>::PriceTooHigh.with_weight(T::DbWeight::get().reads(2))
+			);
+
+			let claim = Self::claim_of(&namespace, &proof)?;
+			let royalty = if claim.creator == seller {
+				Zero::zero()
+			} else {
+				Permill::from_parts(T::RoyaltyBps::get().min(10_000) * 100) * price
+			};
+			if !royalty.is_zero() {
+				T::Currency::transfer(
+					&sender,
+					&claim.creator,
+					royalty,
+					ExistenceRequirement::KeepAlive,
+				)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+				Self::deposit_event(Event::RoyaltyPaid(claim.creator, royalty));
+			}
+			T::Currency::transfer(
+				&sender,
+				&seller,
+				price.saturating_sub(royalty),
+				ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+			// 转移所有权的同时取消出售
+			Self::do_transfer_claim(&namespace, &proof, &seller, &sender, Default::default())?;
+
+			Self::deposit_event(Event::ClaimSold(seller, sender, namespace, proof, price));
+			Ok(().into())
+		}
+
+		/// 由存证的持有者取消出售存证.
+		#[pallet::weight(T::WeightInfo::delist())]
+		pub fn delist(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			// 只有存证的持有者才能取消出售.
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+			Listings::<T>::take(&namespace, &proof).ok_or(Error::<T>::NotForSale)?;
+
+			Self::deposit_event(Event::ClaimDelisted(namespace, proof));
+			Ok(().into())
+		}
+
+		/// 由存证的持有者将存证替换为同一命名空间下新的哈希, 例如更换了哈希算法或修正了错误的哈希.
+		/// 存证的创建者、创建区块号、押金、描述信息和有效期等都会保留到新的存证上.
+		#[pallet::weight(T::WeightInfo::update_claim())]
+		pub fn update_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			old_proof: BoundedVec<u8, T::MaxBytesInHash>,
+			new_proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let old_proof = T::ProofNormalizer::normalize(old_proof);
+			let new_proof = T::ProofNormalizer::normalize(new_proof);
+
+			// 只有存证的持有者才能更新存证.
+			Self::ensure_can_mutate(&sender, ProofOp::Update(&namespace, &old_proof))?;
+			let claim = Self::claim_of(&namespace, &old_proof)?;
+			Self::ensure_not_locked(&namespace, &old_proof)?;
+
+			// 验证新的存证长度合法且尚未被声明.
+			Self::ensure_valid_proof_length(&new_proof)?;
+			ensure!(
+				!Proofs::<T>::contains_key(&namespace, &new_proof),
+				Error::<T>::ProofAlreadyClaimed
+			);
+			ensure!(
+				!RevokedProofs::<T>::contains_key(&namespace, &new_proof),
+				Error::<T>::AlreadyRevoked
+			);
+			// 等待批准的存证只能通过批准生效
+			ensure!(
+				!PendingClaims::<T>::contains_key(&namespace, &new_proof),
+				Error::<T>::ClaimPending
+			);
+
+			Self::rekey_claim(&namespace, &old_proof, &new_proof, &claim)?;
+
+			// 发出一个存证被更新的事件
+			Self::deposit_event(Event::ClaimUpdated(sender, namespace, old_proof, new_proof));
+
+			Ok(().into())
+		}
+
+		/// 由存证的持有者或管理员锁定存证. 锁定期间存证无法被转移、撤销或更新,
+		/// 但仍然可以被管理员强制撤销.
+		#[pallet::weight(T::WeightInfo::lock_claim())]
+		pub fn lock_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_owner_or_admin(origin, &namespace, &proof)?;
+			Self::ensure_not_locked(&namespace, &proof)?;
+
+			LockedProofs::<T>::insert(&namespace, &proof, ());
+
+			// 发出一个存证被锁定的事件
+			Self::deposit_event(Event::ClaimLocked(namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 由存证的持有者或管理员解除存证的锁定.
+		#[pallet::weight(T::WeightInfo::unlock_claim())]
+		pub fn unlock_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_owner_or_admin(origin, &namespace, &proof)?;
+
+			LockedProofs::<T>::take(&namespace, &proof).ok_or(Error::<T>::ClaimNotLocked)?;
+
+			// 发出一个存证被解除锁定的事件
+			Self::deposit_event(Event::ClaimUnlocked(namespace, proof));
+
+			Ok(().into())
+		}
+
+		/// 锁定调用者持有的所有存证的转移, 用于担心私钥泄露的持有者. 锁定期间存证无法通过
+		/// 任何方式被转出, 包括授权的账户和出售, 但持有者仍然可以撤销存证.
+		#[pallet::weight(T::WeightInfo::lock_my_transfers())]
+		pub fn lock_my_transfers(origin: OriginFor<T>) -> DispatchResult {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			ensure!(!AccountTransferLock::<T>::contains_key(&sender), Error::<T>::TransfersLocked);
+
+			AccountTransferLock::<T>::insert(&sender, ());
+
+			// 发出一个账户锁定转移的事件
+			Self::deposit_event(Event::TransfersLocked(sender));
+			Ok(())
+		}
+
+		/// 解除调用者对自己所有存证的转移锁定.
+		#[pallet::weight(T::WeightInfo::unlock_my_transfers())]
+		pub fn unlock_my_transfers(origin: OriginFor<T>) -> DispatchResult {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+
+			AccountTransferLock::<T>::take(&sender).ok_or(Error::<T>::TransfersNotLocked)?;
+
+			// 发出一个账户解除转移锁定的事件
+			Self::deposit_event(Event::TransfersUnlocked(sender));
+			Ok(())
+		}
+
+		/// 由管理员创建命名空间, `admin` 成为命名空间的管理员. 命名空间中最多允许 `max_proofs`
+		/// 个存证, 超出时创建存证返回 `NamespaceFull` 错误. 默认的空命名空间无法被创建,
+		/// 并且只能创建还没有任何存证、待审批存证或撤销记录的命名空间.
+		#[pallet::weight(T::WeightInfo::create_namespace())]
+		pub fn create_namespace(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			admin: T::AccountId,
+			max_proofs: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(!namespace.is_empty(), Error::<T>::DefaultNamespace);
+			ensure!(
+				!NamespaceConfig::<T>::contains_key(&namespace),
+				Error::<T>::NamespaceAlreadyExists
+			);
+			// 已有的存证不计入 `count`, 因此不能为它们补建命名空间
+			ensure!(
+				Proofs::<T>::iter_prefix(&namespace).next().is_none() &&
+					PendingClaims::<T>::iter_prefix(&namespace).next().is_none() &&
+					RevokedProofs::<T>::iter_prefix(&namespace).next().is_none(),
+				Error::<T>::NamespaceInUse
+			);
+
+			NamespaceConfig::<T>::insert(
+				&namespace,
+				NamespaceInfo { admin: admin.clone(), max_proofs, count: 0 },
+			);
+
+			// 发出一个命名空间被创建的事件
+			Self::deposit_event(Event::NamespaceCreated(admin, namespace, max_proofs));
+			Ok(().into())
+		}
+
+		/// 由命名空间的管理员撤销命名空间中的存证, 无需持有者同意, 被锁定的存证也可以被撤销.
+		/// 押金仍然退还给创建者.
+		#[pallet::weight(T::WeightInfo::namespace_force_revoke())]
+		pub fn namespace_force_revoke(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			ensure!(
+				NamespaceConfig::<T>::get(&namespace).map(|info| info.admin).as_ref() ==
+					Some(&sender),
+				Error::<T>::NotNamespaceAdmin.with_weight(Self::lookup_weight())
+			);
+
+			// 从区块中移除存证声明及其关联数据, 存证不存在时返回 `NoSuchProof` 错误.
+			let claim = Self::remove_claim(&namespace, &proof)
+				.ok_or_else(|| Error::<T>::NoSuchProof.with_weight(Self::lookup_weight()))?;
+			Self::note_revoked(&claim.owner);
+			T::OnClaimHandler::on_revoked(&claim.owner, &namespace, &proof);
+
+			// 发出一个存证被命名空间管理员撤销的事件
+			Self::deposit_event(Event::NamespaceClaimRevoked(sender, namespace, proof));
+			Ok(().into())
+		}
+
+		/// 由管理员分批重建布隆过滤器, 以去除已被移除的存证留下的位. 每次调用最多加入 `limit`
+		/// 个且不超过 `MAX_BLOOM_REBUILD_BATCH` 个存证, 下一次调用从停下的存证继续,
+		/// 所有存证都加入后替换当前的过滤器并发出 `BloomRebuilt` 事件. 重建期间创建的存证
+		/// 同时加入两个过滤器, 重建完成之前当前的过滤器仍然有效.
+		#[pallet::weight(T::WeightInfo::rebuild_bloom(limit.min(MAX_BLOOM_REBUILD_BATCH)))]
+		pub fn rebuild_bloom(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(T::BloomBytes::get() > 0, Error::<T>::BloomDisabled);
+
+			let limit = limit.min(MAX_BLOOM_REBUILD_BATCH);
+			let (mut filter, mut last) =
+				BloomRebuild::<T>::take().unwrap_or_else(|| (Self::empty_bloom(), None));
+			let keys = match &last {
+				Some((namespace, proof)) =>
+					Proofs::<T>::iter_keys_from(Proofs::<T>::hashed_key_for(namespace, proof)),
+				None => Proofs::<T>::iter_keys(),
+			};
+
+			let mut added: u32 = 0;
+			for (namespace, proof) in keys.take(limit as usize) {
+				Self::bloom_insert(&mut filter, &namespace, &proof);
+				last = Some((namespace, proof));
+				added += 1;
+			}
+
+			// 没有取满说明所有存证都已加入
+			if added < limit {
+				ProofBloom::<T>::put(filter);
+				Self::deposit_event(Event::BloomRebuilt);
+			} else {
+				BloomRebuild::<T>::put((filter, last));
+			}
+
+			Ok(Some(T::WeightInfo::rebuild_bloom(added)).into())
+		}
+
+		/// 将调用者持有的存证设置为私有或公开. 私有的存证只能由持有者和被授权的查看者通过
+		/// `get_proof_info` 查询, 参见 `PrivateProofs` 对隐私程度的说明.
+		#[pallet::weight(T::WeightInfo::set_private())]
+		pub fn set_private(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			private: bool,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			if private {
+				PrivateProofs::<T>::insert(&namespace, &proof, ());
+			} else {
+				PrivateProofs::<T>::remove(&namespace, &proof);
+			}
+
+			// 发出一个存证的隐私设置变化的事件
+			Self::deposit_event(Event::ClaimPrivacySet(namespace, proof, private));
+			Ok(().into())
+		}
+
+		/// 授权 `viewer` 查看调用者持有的私有存证. 存证转移给新的持有者时授权被清空.
+		#[pallet::weight(T::WeightInfo::grant_view())]
+		pub fn grant_view(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			viewer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			ViewPermissions::<T>::try_mutate(&namespace, &proof, |viewers| {
+				if !viewers.contains(&viewer) {
+					viewers.try_push(viewer.clone()).map_err(|_| Error::<T>::TooManyViewers)?;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 发出一个查看者被授权的事件
+			Self::deposit_event(Event::ViewGranted(sender, namespace, proof, viewer));
+			Ok(().into())
+		}
+
+		/// 取消 `viewer` 查看调用者持有的私有存证的授权.
+		#[pallet::weight(T::WeightInfo::revoke_view())]
+		pub fn revoke_view(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			viewer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 检查 extrinsics 是否已签名，并找到签名者.
+			let sender = ensure_signed(origin)?;
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&sender)?;
+
+			Self::ensure_owner(&namespace, &proof, &sender)?;
+
+			ViewPermissions::<T>::try_mutate_exists(&namespace, &proof, |maybe_viewers| {
+				let viewers = maybe_viewers.as_mut().ok_or(Error::<T>::NotAViewer)?;
+				let index =
+					viewers.iter().position(|who| *who == viewer).ok_or(Error::<T>::NotAViewer)?;
+				viewers.remove(index);
+				if viewers.is_empty() {
+					*maybe_viewers = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 发出一个查看者的授权被取消的事件
+			Self::deposit_event(Event::ViewRevoked(sender, namespace, proof, viewer));
+			Ok(().into())
+		}
+
+		/// 由管理员修改命名空间中最多允许的存证数量. 新的上限可以低于当前的存证数量,
+		/// 此时已有的存证不受影响, 但在数量降到上限以下之前无法再创建存证.
+		#[pallet::weight(T::WeightInfo::set_namespace_max_proofs())]
+		pub fn set_namespace_max_proofs(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			max_proofs: u32,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			NamespaceConfig::<T>::try_mutate(&namespace, |config| {
+				let info = config.as_mut().ok_or(Error::<T>::NoSuchNamespace)?;
+				info.max_proofs = max_proofs;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 发出一个命名空间存证数量上限被修改的事件
+			Self::deposit_event(Event::NamespaceMaxProofsSet(namespace, max_proofs));
+			Ok(())
+		}
+
+		/// 由 `ExpiryScheduler` 在存证的过期区块调用, 移除到期的存证并退还押金, 需要 `ForceOrigin`.
+		/// 存证已被移除、续期或被固定时不做任何修改.
+		#[pallet::weight(T::WeightInfo::handle_expiry())]
+		pub fn handle_expiry(
+			origin: OriginFor<T>,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			if ProofExpiry::<T>::get(&namespace, &proof).map_or(false, |at| at <= now) {
+				Self::expire_claim(&namespace, &proof);
+			}
+			Ok(())
+		}
+
+		/// 响应其他链发来的存证查询, 需要 `QueryOrigin`. 查询结果通过 `QueryResponder`
+		/// 发回发起查询的位置, 并在 `ClaimQueryAnswered` 事件中发出.
+		///
+		/// 发起查询的链向本链发送如下的 XCM 消息:
+		///
+		/// `[WithdrawAsset, BuyExecution, Transact { origin_type: OriginKind::Xcm, call }]`
+		///
+		/// 其中 `call` 是 SCALE 编码的本调用, 包含 pallet 和调用的索引以及 `query_id`、
+		/// `namespace` 和 `proof` 参数. `query_id` 由发起查询的链分配, 用于对应查询和响应.
+		/// 响应与 `verify_query` 相同, 是 SCALE 编码的 `Option<(AccountId, BlockNumber)>`,
+		/// 存证不存在或是私有的时为 `None`.
+		#[pallet::weight(T::WeightInfo::query_claim())]
+		pub fn query_claim(
+			origin: OriginFor<T>,
+			query_id: u64,
+			namespace: NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResult {
+			let location = T::QueryOrigin::ensure_origin(origin)?;
+
+			let proof = T::ProofNormalizer::normalize(proof);
+			let claim = if Self::can_view(&namespace, &proof, None) {
+				Self::verify_claim(&namespace, &proof)
+			} else {
+				None
+			};
+			let response = claim.encode();
+			T::QueryResponder::respond(location, query_id, response.clone())?;
+
+			// 发出一个跨链查询被响应的事件
+			Self::deposit_event(Event::ClaimQueryAnswered(query_id, response));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// 返回存证的持有者和创建时的区块号, 存证不存在、是私有的或超出长度限制时返回 `None`.
+		pub fn proof_info(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Option<(T::AccountId, T::BlockNumber)> {
+			let (namespace, proof) = Self::bounded_key(namespace, proof)?;
+			if !Self::can_view(&namespace, &proof, None) {
+				return None
+			}
+			Self::verify_claim(&namespace, &proof)
+		}
+
+		/// 与 `proof_info` 相同, 但私有的存证对 `viewer` 可见时也返回.
+		/// `signature` 是 `viewer` 对 `view_payload` 的签名, 签名无效时等同于 `proof_info`.
+		///
+		/// 签名不包含时间或随机数, 得到签名的任何人都可以重复使用它查询同一个存证.
+		pub fn proof_info_signed(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+			viewer: T::AccountId,
+			signature: Vec<u8>,
+		) -> Option<(T::AccountId, T::BlockNumber)> {
+			let (namespace, proof) = Self::bounded_key(namespace, proof)?;
+			let signed = T::AuthoritySignature::decode(&mut &signature[..]).map_or(false, |sig| {
+				sig.verify(&Self::view_payload(&namespace, &proof)[..], &viewer)
+			});
+			let viewer = if signed { Some(&viewer) } else { None };
+			if !Self::can_view(&namespace, &proof, viewer) {
+				return None
+			}
+			Self::verify_claim(&namespace, &proof)
+		}
+
+		/// `create_claim_signed` 中存证作者需要签名的内容, `nonce` 为作者当前的 `AccountNonces`.
+		/// 加入前缀和创世区块的哈希, 以免签名被用于其他用途或在其他链上重放.
+		pub fn signed_claim_payload(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			nonce: u64,
+		) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+			(CREATE_PAYLOAD_PREFIX, genesis_hash, namespace, proof, nonce).encode()
+		}
+
+		/// 返回账户下一个签名授权的操作需要使用的随机数.
+		pub fn account_nonce(who: &T::AccountId) -> u64 {
+			AccountNonces::<T>::get(who)
+		}
+
+		/// 查看私有存证时需要签名的内容, 加入前缀以免与 `create_claim_signed` 的签名混淆.
+		pub fn view_payload(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Vec<u8> {
+			(VIEW_PAYLOAD_PREFIX, namespace, proof).encode()
+		}
+
+		/// 返回 `viewer` 能否通过运行时 API 查看存证. 公开的存证任何人都可以查看,
+		/// 私有的存证只有持有者和被授权的查看者可以查看, `viewer` 为 `None` 表示匿名的调用者.
+		pub fn can_view(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			viewer: Option<&T::AccountId>,
+		) -> bool {
+			if !PrivateProofs::<T>::contains_key(namespace, proof) {
+				return true
+			}
+			viewer.map_or(false, |who| {
+				Self::is_owner(namespace, proof, who) ||
+					ViewPermissions::<T>::get(namespace, proof).contains(who)
+			})
+		}
+
+		/// 验证存证是否已被声明, 返回其持有者和创建时的区块号, 存证不存在时返回 `None`.
+		/// 该函数不修改任何状态, 可供其他 pallet 直接调用. 它不检查存证是否私有,
+		/// 面向外部的查询应使用 `proof_info` 或 `verify_query`.
+		pub fn verify_claim(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Option<(T::AccountId, T::BlockNumber)> {
+			Proofs::<T>::get(namespace, proof).map(|claim| (claim.owner, claim.block_number))
+		}
+
+		/// 处理其他链发来的存证查询, 供跨链消息的响应方调用.
+		///
+		/// 请求是 SCALE 编码的 `(namespace: Vec<u8>, proof: Vec<u8>)`, 响应是 SCALE 编码的
+		/// `Option<(AccountId, BlockNumber)>`, 与 `verify_claim` 的返回值相同.
+		/// 无法解码的请求、过长的命名空间或存证以及私有的存证都返回编码后的 `None`.
+		pub fn verify_query(request: &[u8]) -> Vec<u8> {
+			let claim = <(Vec<u8>, Vec<u8>)>::decode(&mut &request[..]).ok().and_then(
+				|(namespace, proof)| {
+					let (namespace, proof) = Self::public_key(namespace, proof)?;
+					Self::verify_claim(&namespace, &proof)
+				},
+			);
+			claim.encode()
+		}
+
+		/// 返回最初创建存证的账户, 存证不存在时返回 `None`.
+		pub fn creator_of(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Option<T::AccountId> {
+			Proofs::<T>::get(namespace, proof).map(|claim| claim.creator)
+		}
+
+		/// 按顺序返回命名空间中的每个存证是否已被声明, 私有的存证视为不存在.
+		/// 最多查询前 `MAX_PROOFS_PER_QUERY` 个存证, 超出的部分被忽略, 因此返回的列表可能比
+		/// `proofs` 短.
+		pub fn proofs_exist(namespace: Vec<u8>, proofs: Vec<Vec<u8>>) -> Vec<bool> {
+			let namespace = NamespaceOf::<T>::try_from(namespace).ok();
+			proofs
+				.into_iter()
+				.take(MAX_PROOFS_PER_QUERY as usize)
+				.map(|proof| {
+					let proof = BoundedVec::<u8, T::MaxBytesInHash>::try_from(proof).ok();
+					match (&namespace, proof) {
+						(Some(namespace), Some(proof)) =>
+							Proofs::<T>::contains_key(namespace, &proof) &&
+								Self::can_view(namespace, &proof, None),
+						_ => false,
+					}
+				})
+				.collect()
+		}
+
+		/// 返回存证的撤销者和撤销时的区块号, 存证没有被撤销或超出长度限制时返回 `None`.
+		pub fn revocation_info(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Option<(T::AccountId, T::BlockNumber)> {
+			let (namespace, proof) = Self::bounded_key(namespace, proof)?;
+			RevokedProofs::<T>::get(&namespace, &proof)
+		}
+
+		/// 返回生成存证时使用的哈希算法, 存证不存在或是私有的时返回 `None`.
+		/// 创建时没有指定算法的存证返回 `HashAlgo::Other(0)`.
+		pub fn hash_algo(namespace: Vec<u8>, proof: Vec<u8>) -> Option<HashAlgo> {
+			let (namespace, proof) = Self::public_key(namespace, proof)?;
+			if !Proofs::<T>::contains_key(&namespace, &proof) {
+				return None
+			}
+			Some(ProofHashAlgo::<T>::get(&namespace, &proof).unwrap_or_default())
+		}
+
+		/// 返回存证的所有属性, 每一项为属性名和属性值. 存证不存在、是私有的或没有设置属性时
+		/// 返回空列表.
+		pub fn attributes_of(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Vec<(AttributeKeyOf<T>, AttributeValueOf<T>)> {
+			match Self::public_key(namespace, proof) {
+				Some(key) => Attributes::<T>::iter_prefix(key).collect(),
+				None => Vec::new(),
+			}
+		}
+
+		/// 按从新到旧的顺序返回最近创建且仍然存在的存证, 每一项为命名空间、存证、创建时的持有者
+		/// 和创建时的区块号. 已被撤销或过期的存证和私有的存证不会出现在结果中,
+		/// 被重新声明的存证只出现一次.
+		pub fn recent_proofs() -> Vec<RecentProofOf<T>> {
+			RecentProofs::<T>::get()
+				.into_iter()
+				.rev()
+				.filter(|(namespace, proof, _, block_number)| {
+					Proofs::<T>::get(namespace, proof)
+						.map_or(false, |claim| claim.block_number == *block_number) &&
+						Self::can_view(namespace, proof, None)
+				})
+				.collect()
+		}
+
+		/// 返回存证内容的 MIME 类型, 存证不存在、是私有的或未设置内容类型时返回 `None`.
+		pub fn content_type(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Option<BoundedVec<u8, T::MaxMimeLen>> {
+			let (namespace, proof) = Self::public_key(namespace, proof)?;
+			ContentTypes::<T>::get(&namespace, &proof)
+		}
+
+		/// 返回存证被创建时的 Unix 时间戳(毫秒), 存证不存在、是私有的或未记录时间时返回 `None`.
+		pub fn proof_timestamp(namespace: Vec<u8>, proof: Vec<u8>) -> Option<u64> {
+			let (namespace, proof) = Self::public_key(namespace, proof)?;
+			ProofTimestamps::<T>::get(&namespace, &proof)
+		}
+
+		/// 返回存证的所有权转移记录, 存证不存在、是私有的或从未被转移时返回空列表.
+		pub fn transfer_history(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Vec<(T::AccountId, T::BlockNumber)> {
+			Self::public_key(namespace, proof)
+				.map(|(namespace, proof)| {
+					TransferHistory::<T>::get(&namespace, &proof).into_inner()
+				})
+				.unwrap_or_default()
+		}
+
+		/// 返回编号对应的命名空间和存证, 没有该编号的存证或存证是私有的时返回 `None`.
+		pub fn proof_by_id(id: u64) -> Option<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)> {
+			ClaimIdToProof::<T>::get(id)
+				.filter(|(namespace, proof)| Self::can_view(namespace, proof, None))
+		}
+
+		/// 返回存证的编号, 存证不存在或是私有的时返回 `None`.
+		pub fn claim_id(namespace: Vec<u8>, proof: Vec<u8>) -> Option<u64> {
+			let (namespace, proof) = Self::public_key(namespace, proof)?;
+			ProofIds::<T>::get(&namespace, &proof)
+		}
+
+		/// 返回取代了存证的新版本存证, 存证没有被取代或任意一个版本是私有的时返回 `None`.
+		pub fn superseded_by(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Option<BoundedVec<u8, T::MaxBytesInHash>> {
+			let (namespace, proof) = Self::public_key(namespace, proof)?;
+			SupersededBy::<T>::get(&namespace, &proof)
+				.filter(|newer| Self::can_view(&namespace, newer, None))
+		}
+
+		/// 从存证开始沿取代关系回溯, 依次返回被它取代的各个旧版本, 最早的版本在最后.
+		/// 最多回溯 `MAX_SUPERSESSION_DEPTH` 个存证, 遇到私有的版本时停止.
+		pub fn supersession_chain(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Vec<BoundedVec<u8, T::MaxBytesInHash>> {
+			let (namespace, mut proof) = match Self::public_key(namespace, proof) {
+				Some(key) => key,
+				None => return Vec::new(),
+			};
+			let mut chain = Vec::new();
+			while let Some(old) = Supersedes::<T>::get(&namespace, &proof) {
+				if chain.len() as u32 >= MAX_SUPERSESSION_DEPTH ||
+					!Self::can_view(&namespace, &old, None)
+				{
+					break
+				}
+				chain.push(old.clone());
+				proof = old;
+			}
+			chain
+		}
+
+		/// 返回命名空间下除私有的存证以外的所有存证, 命名空间超出长度限制时返回空列表.
+		pub fn proofs_in_namespace(namespace: Vec<u8>) -> Vec<BoundedVec<u8, T::MaxBytesInHash>> {
+			NamespaceOf::<T>::try_from(namespace)
+				.map(|namespace| {
+					Proofs::<T>::iter_key_prefix(&namespace)
+						.filter(|proof| Self::can_view(&namespace, proof, None))
+						.collect()
+				})
+				.unwrap_or_default()
+		}
+
+		/// 从 `start_key` 之后开始分页返回所有存证, 每一项为命名空间、存证、持有者和创建时的区块号.
+		/// `start_key` 为 `None` 时从头开始. 单页最多返回 `limit` 个且不超过 `MAX_PROOFS_PAGE_SIZE`
+		/// 个存证, 同时返回用于获取下一页的 `start_key`, 已经没有更多存证时为 `None`.
+		/// 私有的存证不会被返回, 但仍然占用单页的数量, 因此一页可能少于 `limit` 个存证.
+		pub fn list_proofs(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (
+			Vec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, T::AccountId, T::BlockNumber)>,
+			Option<Vec<u8>>,
+		) {
+			let limit = limit.min(MAX_PROOFS_PAGE_SIZE) as usize;
+			let mut iter = match start_key {
+				Some(key) => Proofs::<T>::iter_from(key),
+				None => Proofs::<T>::iter(),
+			};
+
+			let page: Vec<_> = iter.by_ref().take(limit).collect();
+
+			// 还有剩余的存证时, 以本页最后一个存证的存储键作为下一页的起点
+			let next_key = match iter.next() {
+				Some(_) => page
+					.last()
+					.map(|(namespace, proof, _)| Proofs::<T>::hashed_key_for(namespace, proof)),
+				None => None,
+			};
+
+			let page = page
+				.into_iter()
+				.filter(|(namespace, proof, _)| Self::can_view(namespace, proof, None))
+				.map(|(namespace, proof, claim)| {
+					(namespace, proof, claim.owner, claim.block_number)
+				})
+				.collect();
+			(page, next_key)
+		}
+
+		/// 返回创建区块在 `from` 和 `to` 之间(包含两端)的存证, 每一项为命名空间、存证、持有者和
+		/// 创建时的区块号, 最多返回 `MAX_PROOFS_IN_RANGE` 个存证. 私有的存证不会被返回.
+		///
+		/// 该查询会遍历全部存证, 开销与链上的存证数量成正比, 应当只在归档节点上用于审计.
+		pub fn proofs_in_range(
+			from: T::BlockNumber,
+			to: T::BlockNumber,
+		) -> Vec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>, T::AccountId, T::BlockNumber)>
+		{
+			Proofs::<T>::iter()
+				.filter(|(namespace, proof, claim)| {
+					claim.block_number >= from &&
+						claim.block_number <= to &&
+						Self::can_view(namespace, proof, None)
+				})
+				.take(MAX_PROOFS_IN_RANGE as usize)
+				.map(|(namespace, proof, claim)| {
+					(namespace, proof, claim.owner, claim.block_number)
+				})
+				.collect()
+		}
+
+		/// 返回存证在 `Proofs` 中的完整存储键, 命名空间或存证超出长度限制时返回 `None`.
+		///
+		/// 存储键为 `twox_128(pallet 名称) ++ twox_128("Proofs") ++ Blake2_128Concat(namespace)
+		/// ++ ProofKeyHasher(proof)`, 其中命名空间和存证均为 SCALE 编码(带长度前缀)的字节.
+		/// 轻客户端可以用该键通过 `state_getReadProof` 获取存储证明, 并根据区块的状态根验证存证,
+		/// 而无需信任全节点. 存证不存在时同样返回存储键, 存储证明可以证明该键的值不存在.
+		pub fn claim_key(namespace: Vec<u8>, proof: Vec<u8>) -> Option<Vec<u8>> {
+			let (namespace, proof) = Self::bounded_key(namespace, proof)?;
+			Some(Proofs::<T>::hashed_key_for(&namespace, &proof))
+		}
+
+		/// 验证 `leaf` 包含在以 `root` 为根的 Merkle 树中, 且 `root` 已作为存证在 `namespace`
+		/// 下声明.
+		///
+		/// 树的格式如下, 其中 `H` 为声明 `root` 时记录的哈希算法, `||` 表示字节拼接:
+		/// - 叶子节点为 `H(0x00 || leaf)`, `leaf` 为原始的叶子数据
+		/// - 内部节点为 `H(0x01 || left || right)`
+		///
+		/// 叶子和内部节点使用不同的前缀, 因此内部节点或两个节点的拼接无法被当作叶子通过验证.
+		/// `path` 依次为从叶子到根的各层兄弟节点, `true` 表示兄弟节点在左侧.
+		/// 由应用自行约定的算法无法被验证, 路径超过 `MAX_MERKLE_DEPTH` 或 `root` 是私有的存证时
+		/// 同样返回 `false`.
+		pub fn verify_inclusion(
+			namespace: Vec<u8>,
+			root: Vec<u8>,
+			leaf: Vec<u8>,
+			path: Vec<(Vec<u8>, bool)>,
+		) -> bool {
+			if path.len() > MAX_MERKLE_DEPTH {
+				return false
+			}
+			let (namespace, root) = match Self::public_key(namespace, root) {
+				Some(key) => key,
+				None => return false,
+			};
+			if !Proofs::<T>::contains_key(&namespace, &root) {
+				return false
+			}
+			let algo = ProofHashAlgo::<T>::get(&namespace, &root).unwrap_or_default();
+
+			let mut node = match algo.hash(&[&[MERKLE_LEAF_PREFIX][..], &leaf[..]].concat()) {
+				Some(hash) => hash.to_vec(),
+				None => return false,
+			};
+			for (sibling, sibling_on_left) in path {
+				let (left, right) = if sibling_on_left { (sibling, node) } else { (node, sibling) };
+				node = match algo.hash(&[&[MERKLE_NODE_PREFIX][..], &left[..], &right[..]].concat())
+				{
+					Some(hash) => hash.to_vec(),
+					None => return false,
+				};
+			}
+			node == root.into_inner()
+		}
+
+		/// 检查 `who` 现在能否在 `namespace` 下创建存证 `proof`, 不修改任何状态.
+		///
+		/// 依次进行与 `create_claim` 相同的检查:
+		/// 暂停状态、冻结、创建权限和身份、存证规范形式的长度、 是否已被声明、撤销或等待批准、
+		/// 存证数量上限、命名空间的上限、区块内的创建数量限制、
+		/// 过期区块的队列、持有的存证数量和押金.
+		/// 检查失败时返回对应的 `Error` 变体在枚举中的编号, 供钱包在提交交易前给出提示.
+		pub fn can_create(
+			who: &T::AccountId,
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Result<(), u8> {
+			Self::check_create(who, namespace, proof).map_err(Self::error_index)
+		}
+
+		/// 检查 `from` 现在能否将 `namespace` 下的存证 `proof` 转移给 `to`, 不修改任何状态.
+		///
+		/// 依次进行与 `transfer_claim` 相同的检查: 暂停状态、转出者是否被冻结、存证是否存在以及
+		/// 转出者是否是持有者、是否转移给自己、接收者能否持有该存证、转出者是否锁定了转移、
+		/// 存证是否可以转移或被锁定、转移冷却期. 检查失败时返回对应的 `Error` 变体在枚举中的编号.
+		pub fn can_transfer(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Result<(), u8> {
+			Self::check_transfer(from, to, namespace, proof).map_err(Self::error_index)
+		}
+
+		/// 返回 `parent` 的第 `index` 个子账户, 与 `pallet_utility::Pallet::derivative_account_id`
+		/// 的计算方式相同(而不是 `AccountIdConversion`), 因此 `parent` 可以通过 `as_derivative`
+		/// 代替子账户发起调用.
+		pub fn subaccount_of(parent: &T::AccountId, index: u16) -> T::AccountId {
+			let entropy = (b"modlpy/utilisuba", parent, index)
+				.using_encoded(frame_support::sp_io::hashing::blake2_256);
+			Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+				.expect("infinite length input; no invalid inputs for type; qed")
+		}
+
+		/// 根据布隆过滤器判断存证是否可能存在, 不读取 `Proofs`. 返回 `false` 时存证一定不存在,
+		/// 返回 `true` 时存证可能存在, 需要通过 `get_proof_info` 等查询确认.
+		/// 没有启用或尚未建立布隆过滤器时总是返回 `true`.
+		pub fn bloom_might_contain(namespace: Vec<u8>, proof: Vec<u8>) -> bool {
+			let (namespace, proof) = match Self::bounded_key(namespace, proof) {
+				Some(key) => key,
+				None => return false,
+			};
+			let filter = ProofBloom::<T>::get();
+			if filter.is_empty() {
+				return true
+			}
+			Self::bloom_positions(&namespace, &proof, filter.len())
+				.iter()
+				.all(|&bit| filter[bit / 8] & (1 << (bit % 8)) != 0)
+		}
+
+		/// 返回账户的存证统计.
+		pub fn stats_of(account: &T::AccountId) -> ClaimStats {
+			AccountStats::<T>::get(account)
+		}
+
+		/// 返回等待批准的存证的状态, 没有等待批准的存证时返回 `None`.
+		pub fn pending_claim_status(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Option<PendingClaimStatus> {
+			PendingClaims::<T>::get(namespace, proof).map(|pending| {
+				if <frame_system::Pallet<T>>::block_number() > pending.expires_at {
+					PendingClaimStatus::Expired
+				} else {
+					PendingClaimStatus::ThresholdNotMet
+				}
+			})
+		}
+
+		/// 返回当前存在的存证总数.
+		pub fn total_proofs() -> u32 {
+			ProofCount::<T>::get()
+		}
+
+		/// 返回当前存在的存证保留的押金总额.
+		pub fn total_deposits() -> BalanceOf<T> {
+			TotalReservedDeposits::<T>::get()
+		}
+
+		/// 返回在达到 `MaxProofs` 之前还可以创建的存证数量.
+		pub fn remaining_capacity() -> u32 {
+			T::MaxProofs::get().saturating_sub(ProofCount::<T>::get())
+		}
+
+		/// 返回 pallet 当前是否已被暂停.
+		pub fn is_paused() -> bool {
+			Paused::<T>::get()
+		}
+
+		/// 返回命名空间中带有 `tag` 标签的所有公开的存证, 命名空间或标签超出长度限制时
+		/// 返回空列表.
+		pub fn proofs_by_tag(
+			namespace: Vec<u8>,
+			tag: Vec<u8>,
+		) -> Vec<BoundedVec<u8, T::MaxBytesInHash>> {
+			match (NamespaceOf::<T>::try_from(namespace), TagOf::<T>::try_from(tag)) {
+				(Ok(namespace), Ok(tag)) =>
+					TagIndex::<T>::iter_key_prefix((namespace.clone(), tag))
+						.filter(|proof| Self::can_view(&namespace, proof, None))
+						.collect(),
+				_ => Vec::new(),
+			}
+		}
+
+		/// 返回 `account` 当前持有的所有公开的存证及其命名空间.
+		pub fn claims_of(
+			account: &T::AccountId,
+		) -> Vec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)> {
+			ClaimsOf::<T>::get(account)
+				.into_iter()
+				.filter(|(namespace, proof)| Self::can_view(namespace, proof, None))
+				.collect()
+		}
+
+		/// `can_create` 的实现, 返回第一个不满足的检查对应的错误.
+		fn check_create(who: &T::AccountId, namespace: Vec<u8>, proof: Vec<u8>) -> DispatchResult {
+			ensure!(!Paused::<T>::get(), Error::<T>::PalletPaused);
+			Self::ensure_not_frozen(who)?;
+			Self::ensure_can_create(who)?;
+
+			let namespace =
+				NamespaceOf::<T>::try_from(namespace).map_err(|_| Error::<T>::NamespaceTooLong)?;
+			let proof = BoundedVec::<u8, T::MaxBytesInHash>::try_from(proof)
+				.map_err(|_| Error::<T>::ProofTooLong)?;
+			// 与 `create_claim` 一样检查存证的规范形式
+			let proof = T::ProofNormalizer::normalize(proof);
+			Self::ensure_valid_proof_length(&proof)?;
+			ensure!(
+				!Proofs::<T>::contains_key(&namespace, &proof),
+				Error::<T>::ProofAlreadyClaimed
+			);
+			ensure!(
+				!RevokedProofs::<T>::contains_key(&namespace, &proof),
+				Error::<T>::AlreadyRevoked
+			);
+			ensure!(
+				!PendingClaims::<T>::contains_key(&namespace, &proof),
+				Error::<T>::ClaimPending
+			);
+			ensure!(ProofCount::<T>::get() < T::MaxProofs::get(), Error::<T>::StorageFull);
+			ensure!(
+				NamespaceConfig::<T>::get(&namespace)
+					.map_or(true, |info| info.count < info.max_proofs),
+				Error::<T>::NamespaceFull
+			);
+
+			let limit = T::MaxCreationsPerBlock::get();
+			ensure!(limit == 0 || Self::creations_this_block(who) < limit, Error::<T>::RateLimited);
+			let ttl = T::ClaimTtl::get();
+			if !ttl.is_zero() {
+				let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(ttl);
+				ensure!(
+					(ExpiryQueue::<T>::decode_len(expires_at).unwrap_or(0) as u32) <
+						T::MaxExpiredPerBlock::get(),
+					Error::<T>::TooManyExpiries
+				);
+			}
+			ensure!(
+				(ClaimsOf::<T>::decode_len(who).unwrap_or(0) as u32) <
+					T::MaxClaimsPerAccount::get(),
+				Error::<T>::ExceededMaxClaims
+			);
+			ensure!(
+				T::Currency::can_reserve(who, T::ClaimDeposit::get()),
+				Error::<T>::InsufficientDeposit
+			);
+			Ok(())
+		}
+
+		/// `can_transfer` 的实现, 返回第一个不满足的检查对应的错误.
+		fn check_transfer(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> DispatchResult {
+			ensure!(!Paused::<T>::get(), Error::<T>::PalletPaused);
+			Self::ensure_not_frozen(from)?;
+
+			let (namespace, proof) =
+				Self::bounded_key(namespace, proof).ok_or(Error::<T>::NoSuchProof)?;
+			ensure!(Proofs::<T>::contains_key(&namespace, &proof), Error::<T>::NoSuchProof);
+			ensure!(Self::is_owner(&namespace, &proof, from), Error::<T>::NotProofOwner);
+
+			ensure!(from != to, Error::<T>::CannotTransferToSelf);
+			Self::ensure_can_receive(&namespace, &proof, to)?;
+			ensure!(!AccountTransferLock::<T>::contains_key(from), Error::<T>::TransfersLocked);
+			ensure!(
+				!Soulbound::<T>::contains_key(&namespace, &proof),
+				Error::<T>::ClaimNotTransferable
+			);
+			Self::ensure_not_locked(&namespace, &proof)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let cooldown = T::TransferCooldown::get();
+			if !cooldown.is_zero() {
+				if let Some(last) = LastTransferAt::<T>::get(&namespace, &proof) {
+					ensure!(now >= last.saturating_add(cooldown), Error::<T>::TransferTooSoon);
+				}
+			}
+			ensure!(
+				(TransferHistory::<T>::decode_len(&namespace, &proof).unwrap_or(0) as u32) <
+					T::MaxHistoryLength::get(),
+				Error::<T>::HistoryFull
+			);
+			Ok(())
+		}
+
+		/// 返回本 pallet 的错误在 `Error` 枚举中的编号, 其他错误返回 `u8::MAX`.
+		fn error_index(error: DispatchError) -> u8 {
+			match error {
+				// 依次编码为变体编号、pallet 在运行时中的编号和错误的编号
+				DispatchError::Module(_) => error.encode().get(2).copied().unwrap_or(u8::MAX),
+				_ => u8::MAX,
+			}
+		}
+
+		/// 将运行时 API 传入的命名空间和存证转换为存储使用的类型和存证的规范形式,
+		/// 超出长度限制时返回 `None`.
+		fn bounded_key(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Option<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)> {
+			let namespace = NamespaceOf::<T>::try_from(namespace).ok()?;
+			let proof = BoundedVec::<u8, T::MaxBytesInHash>::try_from(proof).ok()?;
+			Some((namespace, T::ProofNormalizer::normalize(proof)))
+		}
+
+		/// 与 `bounded_key` 相同, 但存证对匿名的调用者不可见时同样返回 `None`.
+		fn public_key(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Option<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)> {
+			Self::bounded_key(namespace, proof)
+				.filter(|(namespace, proof)| Self::can_view(namespace, proof, None))
+		}
+
+		/// 为 `owner` 在 `namespace` 下创建一个存证并从 `depositor` 处保留 `ClaimDeposit`,
+		/// 然后发出 `ClaimCreated` 事件. `depositor` 被记录为存证的创建者.
+		fn do_create_claim(
+			owner: &T::AccountId,
+			depositor: &T::AccountId,
+			namespace: &NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResult {
+			Self::do_create_claim_with_deposit(
+				owner,
+				depositor,
+				namespace,
+				proof,
+				T::ClaimDeposit::get(),
+			)
+		}
+
+		/// 与 `do_create_claim` 相同, 但保留的押金为 `deposit`. 押金为 0 的存证在被移除时
+		/// 不会解除保留任何金额.
+		///
+		/// 该函数是事务性的, 返回错误时不会留下部分修改的状态.
+		#[transactional]
+		fn do_create_claim_with_deposit(
+			owner: &T::AccountId,
+			depositor: &T::AccountId,
+			namespace: &NamespaceOf<T>,
+			proof: BoundedVec<u8, T::MaxBytesInHash>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			// 验证存证的长度是否合法
+			Self::ensure_valid_proof_length(&proof)?;
+
+			// 验证指定的存证在该命名空间下是否尚未声明, 也没有被撤销。
+			ensure!(!Proofs::<T>::contains_key(namespace, &proof), Error::<T>::ProofAlreadyClaimed);
+			ensure!(
+				!RevokedProofs::<T>::contains_key(namespace, &proof),
+				Error::<T>::AlreadyRevoked
+			);
+			// 等待批准的存证只能通过批准生效
+			ensure!(!PendingClaims::<T>::contains_key(namespace, &proof), Error::<T>::ClaimPending);
+
+			// 链上的存证数量不能超过上限
+			ensure!(ProofCount::<T>::get() < T::MaxProofs::get(), Error::<T>::StorageFull);
+
+			// 已创建的命名空间中的存证数量不能超过命名空间的上限
+			NamespaceConfig::<T>::try_mutate(namespace, |config| {
+				if let Some(info) = config {
+					ensure!(info.count < info.max_proofs, Error::<T>::NamespaceFull);
+					info.count += 1;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			// 限制每个账户在一个区块内可以创建的存证数量, 代为提交的存证计入持有者
+			let limit = T::MaxCreationsPerBlock::get();
+			let created = Self::creations_this_block(owner);
+			ensure!(limit == 0 || created < limit, Error::<T>::RateLimited);
+
+			// 从 FRAME System pallet 获取区块号.
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			CreationsThisBlock::<T>::insert(owner, (current_block, created.saturating_add(1)));
+
+			// 有效期不为 0 时, 将存证加入过期区块的队列
+			let ttl = T::ClaimTtl::get();
+			if !ttl.is_zero() {
+				let expires_at = current_block.saturating_add(ttl);
+				Self::enqueue_expiry(expires_at, namespace, &proof)?;
+				ProofExpiry::<T>::insert(namespace, &proof, expires_at);
+			}
+
+			// 将存证加入持有者的存证列表
+			ClaimsOf::<T>::try_mutate(owner, |claims| {
+				claims
+					.try_push((namespace.clone(), proof.clone()))
+					.map_err(|_| Error::<T>::ExceededMaxClaims)
+			})?;
+
+			// 保留存证押金
+			T::Currency::reserve(depositor, deposit)
+				.map_err(|_| Error::<T>::InsufficientDeposit)?;
+			if !deposit.is_zero() {
+				TotalReservedDeposits::<T>::mutate(|total| *total = total.saturating_add(deposit));
+				Self::deposit_event(Event::DepositReserved(
+					depositor.clone(),
+					namespace.clone(),
+					proof.clone(),
+					deposit,
+				));
+			}
+
+			// 存储存证中的 发送者、区块号 和 押金
+			Proofs::<T>::insert(
+				namespace,
+				&proof,
+				ClaimInfo {
+					owner: owner.clone(),
+					creator: depositor.clone(),
+					block_number: current_block,
+					deposit,
+				},
+			);
+			ProofCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			Self::assign_claim_id(namespace, &proof);
+			if T::ClaimEviction::get() {
+				Self::set_priority(namespace, &proof, 0);
+			}
+
+			// 记录存证创建时的时间戳, 时间来源不可用时返回 0
+			let now = T::UnixTime::now().as_millis() as u64;
+			if now != 0 {
+				ProofTimestamps::<T>::insert(namespace, &proof, now);
+			}
+
+			// 记录新创建的存证供链下工作机推送, 超出上限的存证不会被推送
+			if T::ClaimNotifications::get() &&
+				NewClaims::<T>::mutate(|claims| {
+					claims.try_push((namespace.clone(), proof.clone()))
+				})
+				.is_err()
+			{
+				log::warn!(target: "runtime::poe", "too many new claims in block, not notifying");
+			}
+
+			AccountStats::<T>::mutate(owner, |stats| {
+				stats.active = stats.active.saturating_add(1);
+				stats.created = stats.created.saturating_add(1);
+			});
+
+			Self::note_in_bloom(namespace, &proof);
+
+			// 记录到最近创建的存证中, 已满时丢弃最早的存证
+			RecentProofs::<T>::mutate(|recent| {
+				if !recent.is_empty() && recent.len() as u32 >= T::RecentBufferLen::get() {
+					recent.remove(0);
+				}
+				let _ = recent.try_push((
+					namespace.clone(),
+					proof.clone(),
+					owner.clone(),
+					current_block,
+				));
+			});
+
+			T::OnClaimHandler::on_created(owner, namespace, &proof);
+
+			// 发出一个存证被创建的事件. 存证曾经过期时改为发出重新声明的事件, 以保留存证的来源
+			ExpiredRecordIndex::<T>::remove(namespace, &proof);
+			match ExpiredProofs::<T>::take(namespace, &proof) {
+				Some(previous_owner) => Self::deposit_event(Event::ClaimReanchored(
+					owner.clone(),
+					previous_owner,
+					namespace.clone(),
+					proof,
+					deposit,
+					current_block,
+				)),
+				None => Self::deposit_event(Event::ClaimCreated(
+					owner.clone(),
+					namespace.clone(),
+					proof,
+					deposit,
+					current_block,
+				)),
+			}
+
+			Ok(())
+		}
+
+		/// 存储(或覆盖)存证的描述信息, 并返回递增后的描述信息版本.
+		fn do_set_metadata(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			metadata: BoundedVec<u8, T::MaxMetadataLength>,
+		) -> u32 {
+			ProofMetadata::<T>::insert(namespace, proof, metadata);
+			MetadataVersion::<T>::mutate(namespace, proof, |version| {
+				*version = version.saturating_add(1);
+				*version
+			})
+		}
+
+		/// 将存证的所有权从 `from` 转移给 `to`, 并发出带有备注 `memo` 的 `ClaimTransferred` 事件.
+		///
+		/// 存证的押金仍然从创建者处保留, 不随所有权转移. 备注只记录在事件中, 不写入存储.
+		/// 调用者需要事先验证 `from` 是存证的持有者.
+		#[transactional]
+		fn do_transfer_claim(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			memo: BoundedVec<u8, T::MaxMemoLen>,
+		) -> DispatchResult {
+			// 转移给自己不会产生任何变化, 直接拒绝以免发出误导性的事件.
+			ensure!(from != to, Error::<T>::CannotTransferToSelf);
+
+			// 被冻结的账户既不能转出也不能接收存证.
+			Self::ensure_not_frozen(from)?;
+			Self::ensure_can_receive(namespace, proof, to)?;
+
+			// 持有者锁定了转移时, 任何人都不能转出其存证.
+			ensure!(!AccountTransferLock::<T>::contains_key(from), Error::<T>::TransfersLocked);
+
+			// 灵魂绑定的存证无法被转移.
+			ensure!(
+				!Soulbound::<T>::contains_key(namespace, proof),
+				Error::<T>::ClaimNotTransferable
+			);
+			Self::ensure_not_locked(namespace, proof)?;
+
+			// 在冷却期内不能再次转移, 每次成功转移后重新开始计算冷却期
+			let now = <frame_system::Pallet<T>>::block_number();
+			let cooldown = T::TransferCooldown::get();
+			if !cooldown.is_zero() {
+				if let Some(last) = LastTransferAt::<T>::get(namespace, proof) {
+					ensure!(now >= last.saturating_add(cooldown), Error::<T>::TransferTooSoon);
+				}
+				LastTransferAt::<T>::insert(namespace, proof, now);
+			}
+
+			// 共有存证只替换转出者的份额
+			if let Some(mut owners) = SharedOwners::<T>::get(namespace, proof) {
+				let slot =
+					owners.iter_mut().find(|owner| *owner == from).ok_or(Error::<T>::NotAnOwner)?;
+				*slot = to.clone();
+				SharedOwners::<T>::insert(namespace, proof, owners);
+				RevokeApprovals::<T>::mutate(namespace, proof, |approvals| {
+					approvals.retain(|who| who != from)
+				});
+			}
+
+			// 更新双方持有的存证列表
+			Self::remove_from_claims_of(from, namespace, proof);
+			ClaimsOf::<T>::try_mutate(to, |claims| {
+				claims
+					.try_push((namespace.clone(), proof.clone()))
+					.map_err(|_| Error::<T>::ExceededMaxClaims)
+			})?;
+
+			// 从区块中转移存证所有权, 保留存证的创建者和创建时的区块号
+			let owner_changed = Proofs::<T>::try_mutate(
+				namespace,
+				proof,
+				|maybe_claim| -> Result<bool, DispatchError> {
+					let claim = maybe_claim.as_mut().ok_or(Error::<T>::NoSuchProof)?;
+					if claim.owner != *from {
+						return Ok(false)
+					}
+					claim.owner = to.clone();
+					Ok(true)
+				},
+			)?;
+
+			// 新的持有者不继承之前的持有者授权的描述信息编辑者和查看者
+			if owner_changed {
+				MetadataEditors::<T>::remove(namespace, proof);
+				ViewPermissions::<T>::remove(namespace, proof);
+				AccountStats::<T>::mutate(from, |stats| {
+					stats.active = stats.active.saturating_sub(1)
+				});
+				AccountStats::<T>::mutate(to, |stats| {
+					stats.active = stats.active.saturating_add(1)
+				});
+			}
+
+			// 所有权转移后, 之前的转移授权和待接收的转移不再有效
+			if Approvals::<T>::take(namespace, proof).is_some() {
+				Self::deposit_event(Event::ApprovalCleared(namespace.clone(), proof.clone()));
+			}
+			PendingTransfers::<T>::remove(namespace, proof);
+			Self::release_transfer_bond(namespace, proof);
+			if Listings::<T>::take(namespace, proof).is_some() {
+				Self::deposit_event(Event::ClaimDelisted(namespace.clone(), proof.clone()));
+			}
+
+			// 记录本次转移, 转移记录已满时返回 `HistoryFull` 错误而不是丢弃旧的记录
+			TransferHistory::<T>::try_mutate(namespace, proof, |history| {
+				history.try_push((to.clone(), now)).map_err(|_| Error::<T>::HistoryFull)
+			})?;
+
+			T::OnClaimHandler::on_transferred(from, to, namespace, proof);
+
+			// 发出一个存证所有权转移的事件
+			Self::deposit_event(Event::ClaimTransferred(
+				from.clone(),
+				to.clone(),
+				namespace.clone(),
+				proof.clone(),
+				now,
+				memo,
+			));
+
+			Ok(())
+		}
+
+		/// 转移存证时对接收者的检查, 返回第一个不满足的检查对应的错误: 接收者被冻结
+		/// (`AccountFrozen`)、不在存证的接收者名单中(`RecipientNotAllowed`)、已经是共有存证的
+		/// 持有者(`DuplicateOwner`)或持有的存证已达到 `MaxClaimsPerAccount`(`ExceededMaxClaims`).
+		fn ensure_can_receive(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			to: &T::AccountId,
+		) -> DispatchResult {
+			Self::ensure_not_frozen(to)?;
+
+			// 设置了接收者名单的存证只能被转移给名单中的账户.
+			let allowlist = TransferAllowlist::<T>::get(namespace, proof);
+			ensure!(
+				allowlist.is_empty() || allowlist.contains(to),
+				Error::<T>::RecipientNotAllowed
+			);
+
+			// 共有存证的接收者不能已经是持有者
+			if let Some(owners) = SharedOwners::<T>::get(namespace, proof) {
+				ensure!(!owners.contains(to), Error::<T>::DuplicateOwner);
+			}
+
+			ensure!(
+				(ClaimsOf::<T>::decode_len(to).unwrap_or(0) as u32) < T::MaxClaimsPerAccount::get(),
+				Error::<T>::ExceededMaxClaims
+			);
+			Ok(())
+		}
+
+		/// 返回 `who` 在当前区块内作为持有者创建的存证数量.
+		fn creations_this_block(who: &T::AccountId) -> u32 {
+			let (block_number, created) = CreationsThisBlock::<T>::get(who);
+			if block_number == <frame_system::Pallet<T>>::block_number() {
+				created
+			} else {
+				0
+			}
+		}
+
+		/// 存证的长度不在 `MinBytesInHash` 和 `MaxBytesInHash` 之间时返回错误.
+		fn ensure_valid_proof_length(proof: &BoundedVec<u8, T::MaxBytesInHash>) -> DispatchResult {
+			let len = proof.len() as u32;
+			ensure!(
+				len >= T::MinBytesInHash::get() && len <= T::MaxBytesInHash::get(),
+				Error::<T>::InvalidProofLength
+			);
+			Ok(())
+		}
+
+		/// 创建、转移和撤销存证的前置检查, 按以下顺序进行并返回第一个不满足的检查对应的错误:
+		///
+		/// 1. pallet 被暂停时返回 `PalletPaused`;
+		/// 2. `sender` 被冻结时返回 `AccountFrozen`;
+		/// 3. `sender` 无权进行 `op` 时返回对应的错误: 创建存证时检查存证创建者名单和身份
+		///    (`NotAuthorizedToCreate`、`IdentityRequired`), 转移存证时检查 `sender` 是否是存证的
+		///    持有者, 撤销存证时持有者的撤销代理也满足条件(`NoSuchProof`、`NotProofOwner`);
+		///    `transfer_from` 中被授权的账户也满足条件, 存证已授权给其他账户时返回 `NotApproved`;
+		///    更新存证时只有第一个持有者满足条件; 批量操作不在这里检查授权.
+		///
+		/// 检查的顺序决定了用户看到的错误, 因此这些可调度函数都应当通过它进行检查, 而不是分别调用
+		/// 各个检查, 例如 pallet 被暂停时即使调用者无权操作也总是返回 `PalletPaused`.
+		fn ensure_can_mutate(
+			sender: &T::AccountId,
+			op: ProofOp<T>,
+		) -> Result<(), DispatchErrorWithPostInfo> {
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(sender)?;
+			match op {
+				ProofOp::Create => Self::ensure_can_create(sender)?,
+				ProofOp::Transfer(namespace, proof) =>
+					if !Self::is_owner(namespace, proof, sender) {
+						Self::ensure_owner(namespace, proof, sender)?;
+					},
+				ProofOp::Revoke(namespace, proof) => {
+					let claim = Self::claim_of(namespace, proof)?;
+					ensure!(
+						Self::is_owner(namespace, proof, sender) ||
+							RevokeDelegates::<T>::contains_key(&claim.owner, sender),
+						Error::<T>::NotProofOwner.with_weight(Self::lookup_weight())
+					);
+				},
+				ProofOp::TransferFrom(namespace, proof) => {
+					let claim = Self::claim_of(namespace, proof)?;
+					if *sender != claim.owner {
+						match Approvals::<T>::get(namespace, proof) {
+							Some(spender) => ensure!(
+								spender == *sender,
+								Error::<T>::NotApproved.with_weight(Self::lookup_weight())
+							),
+							None =>
+								return Err(
+									Error::<T>::NotProofOwner.with_weight(Self::lookup_weight())
+								),
+						}
+					}
+				},
+				ProofOp::Update(namespace, proof) => {
+					Self::ensure_owner(namespace, proof, sender)?;
+				},
+				ProofOp::Batch => (),
+			}
+			Ok(())
+		}
+
+		/// 启用 `PermissionedCreation` 且 `who` 不在存证创建者名单中,
+		/// 或启用 `RequireIdentity` 且 `who` 没有登记身份时返回错误. 不检查 `who` 是否被冻结.
+		fn ensure_can_create(who: &T::AccountId) -> DispatchResult {
+			ensure!(
+				!T::PermissionedCreation::get() || AllowedCreators::<T>::contains_key(who),
+				Error::<T>::NotAuthorizedToCreate
+			);
+			ensure!(
+				!T::RequireIdentity::get() || T::IdentityProvider::has_identity(who),
+				Error::<T>::IdentityRequired
+			);
+			Ok(())
+		}
+
+		/// `who` 已被管理员冻结时返回 `AccountFrozen` 错误.
+		fn ensure_not_frozen(who: &T::AccountId) -> DispatchResult {
+			ensure!(!FrozenAccounts::<T>::contains_key(who), Error::<T>::AccountFrozen);
+			Ok(())
+		}
+
+		/// pallet 被暂停时返回 `PalletPaused` 错误, 并只收取读取暂停状态所需的权重.
+		fn ensure_not_paused() -> Result<(), DispatchErrorWithPostInfo> {
+			ensure!(
+				!Paused::<T>::get(),
+				Error::<T>::PalletPaused.with_weight(T::DbWeight::get().reads(1))
+			);
+			Ok(())
+		}
+
+		/// 检查暂停状态并读取一个存储项所需的权重, 用于调用提前失败时退还多余的权重.
+		fn lookup_weight() -> Weight {
+			T::DbWeight::get().reads(2)
+		}
+
+		/// 返回存证的持有者信息, 存证不存在时返回 `NoSuchProof` 错误并只收取查询所需的权重.
+		fn claim_of(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Result<ClaimInfoOf<T>, DispatchErrorWithPostInfo> {
+			Proofs::<T>::get(namespace, proof)
+				.ok_or_else(|| Error::<T>::NoSuchProof.with_weight(Self::lookup_weight()))
+		}
+
+		/// 返回存证的持有者信息, `who` 不是存证的持有者时返回 `NotProofOwner` 错误
+		/// 并只收取查询所需的权重.
+		fn ensure_owner(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			who: &T::AccountId,
+		) -> Result<ClaimInfoOf<T>, DispatchErrorWithPostInfo> {
+			let claim = Self::claim_of(namespace, proof)?;
+			ensure!(
+				*who == claim.owner,
+				Error::<T>::NotProofOwner.with_weight(Self::lookup_weight())
+			);
+			Ok(claim)
+		}
+
+		/// 检查 `who` 是否是存证的持有者, 对于共有存证, 任意一个持有者都满足条件.
+		fn is_owner(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			who: &T::AccountId,
+		) -> bool {
+			match SharedOwners::<T>::get(namespace, proof) {
+				Some(owners) => owners.contains(who),
+				None =>
+					Proofs::<T>::get(namespace, proof).map_or(false, |claim| claim.owner == *who),
+			}
+		}
+
+		/// 共有存证在所有持有者都同意撤销之前返回 `RevokeNotApproved` 错误, 普通存证总是通过.
+		fn ensure_revoke_approved(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Result<(), DispatchErrorWithPostInfo> {
+			if let Some(owners) = SharedOwners::<T>::get(namespace, proof) {
+				let approvals = RevokeApprovals::<T>::get(namespace, proof);
+				ensure!(
+					owners.iter().all(|owner| approvals.contains(owner)),
+					Error::<T>::RevokeNotApproved.with_weight(
+						Self::lookup_weight().saturating_add(T::DbWeight::get().reads(2))
+					)
+				);
+			}
+			Ok(())
+		}
+
+		/// 存证已被声明时返回 `ProofAlreadyClaimed` 错误, 已被撤销时返回 `AlreadyRevoked` 错误,
+		/// 并只收取查询所需的权重.
+		fn ensure_unclaimed(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Result<(), DispatchErrorWithPostInfo> {
+			ensure!(
+				!Proofs::<T>::contains_key(namespace, proof),
+				Error::<T>::ProofAlreadyClaimed.with_weight(Self::lookup_weight())
+			);
+			ensure!(
+				!RevokedProofs::<T>::contains_key(namespace, proof),
+				Error::<T>::AlreadyRevoked
+					.with_weight(Self::lookup_weight().saturating_add(T::DbWeight::get().reads(1)))
+			);
+			Ok(())
+		}
+
+		/// 存证被锁定时返回 `ClaimLocked` 错误.
+		fn ensure_not_locked(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResult {
+			ensure!(!LockedProofs::<T>::contains_key(namespace, proof), Error::<T>::ClaimLocked);
+			Ok(())
+		}
+
+		/// 验证调用来自管理员, 或者来自存证的持有者且 pallet 未被暂停, 返回存证的持有者信息.
+		fn ensure_owner_or_admin(
+			origin: OriginFor<T>,
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Result<ClaimInfoOf<T>, DispatchErrorWithPostInfo> {
+			match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => Self::claim_of(namespace, proof),
+				Err(origin) => {
+					let sender = ensure_signed(origin)?;
+					Self::ensure_not_paused()?;
+					Self::ensure_owner(namespace, proof, &sender)
+				},
+			}
+		}
+
+		/// 延长 `who` 持有的存证的有效期并收取 `RenewalFee`. `additional_blocks` 为 `None` 时
+		/// 有效期从当前区块起重新计算, 否则在原来的过期区块上延长 `additional_blocks` 个区块.
+		#[transactional]
+		fn do_renew_claim(
+			who: &T::AccountId,
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			additional_blocks: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			// 只有存证的持有者才能延长有效期.
+			Self::ensure_owner(namespace, proof, who).map_err(|e| e.error)?;
+
+			let old_expiry =
+				ProofExpiry::<T>::get(namespace, proof).ok_or(Error::<T>::ClaimDoesNotExpire)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			// 不自动清理时, 已经过期但尚未被清理的存证不能被延长有效期
+			ensure!(old_expiry > now, Error::<T>::ClaimAlreadyExpired);
+			let new_expiry = match additional_blocks {
+				Some(blocks) => old_expiry.saturating_add(blocks),
+				None => now.saturating_add(T::ClaimTtl::get()),
+			};
+
+			// 收取续期费用
+			let fee = T::RenewalFee::get();
+			if !fee.is_zero() {
+				let imbalance = T::Currency::withdraw(
+					who,
+					fee,
+					WithdrawReasons::FEE,
+					ExistenceRequirement::KeepAlive,
+				)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+				T::OnRenewalFee::on_unbalanced(imbalance);
+				Self::deposit_event(Event::RenewalFeePaid(
+					who.clone(),
+					namespace.clone(),
+					proof.clone(),
+					fee,
+				));
+			}
+
+			// 先取消原来的调度任务, 再加入新的过期队列
+			Self::dequeue_expiry(old_expiry, namespace, proof);
+			Self::enqueue_expiry(new_expiry, namespace, proof)?;
+			ProofExpiry::<T>::insert(namespace, proof, new_expiry);
+			ExpiryWarned::<T>::remove(namespace, proof);
+
+			// 发出一个存证有效期被延长的事件
+			Self::deposit_event(Event::ClaimRenewed(
+				who.clone(),
+				namespace.clone(),
+				proof.clone(),
+				new_expiry,
+			));
+
+			Ok(())
+		}
+
+		/// 在 `who` 的存证统计中记录一个被撤销的存证, 存证本身由 `remove_claim` 移除.
+		fn note_revoked(who: &T::AccountId) {
+			AccountStats::<T>::mutate(who, |stats| stats.revoked = stats.revoked.saturating_add(1));
+		}
+
+		/// 移除存证及其描述信息、有效期等关联数据, 并将押金退还给创建者.
+		fn remove_claim(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Option<ClaimInfoOf<T>> {
+			let claim = Self::take_claim(namespace, proof)?;
+			Self::unreserve_deposit(&claim.creator, namespace, proof, claim.deposit);
+			Some(claim)
+		}
+
+		/// 解除保留存证的押金并从 `TotalReservedDeposits` 中扣除, 押金为 0 时不做任何操作.
+		fn unreserve_deposit(
+			creator: &T::AccountId,
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			deposit: BalanceOf<T>,
+		) {
+			if deposit.is_zero() {
+				return
+			}
+			T::Currency::unreserve(creator, deposit);
+			TotalReservedDeposits::<T>::mutate(|total| *total = total.saturating_sub(deposit));
+			Self::deposit_event(Event::DepositUnreserved(
+				creator.clone(),
+				namespace.clone(),
+				proof.clone(),
+				deposit,
+			));
+		}
+
+		/// 从 `creator` 保留的余额中罚没存证的押金并交给 `OnSlash` 处理, 同时从
+		/// `TotalReservedDeposits` 中扣除, 返回实际罚没的金额.
+		fn slash_deposit(creator: &T::AccountId, deposit: BalanceOf<T>) -> BalanceOf<T> {
+			let (imbalance, _) = T::Currency::slash_reserved(creator, deposit);
+			let amount = imbalance.peek();
+			T::OnSlash::on_unbalanced(imbalance);
+			TotalReservedDeposits::<T>::mutate(|total| *total = total.saturating_sub(deposit));
+			amount
+		}
+
+		/// 与 `remove_claim` 相同, 但不退还押金, 押金仍然计入 `TotalReservedDeposits`.
+		fn take_claim(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> Option<ClaimInfoOf<T>> {
+			let claim = Proofs::<T>::take(namespace, proof)?;
+			ProofCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			NamespaceConfig::<T>::mutate(namespace, |config| {
+				if let Some(info) = config {
+					info.count = info.count.saturating_sub(1);
+				}
+			});
+			Self::remove_from_claims_of(&claim.owner, namespace, proof);
+			AccountStats::<T>::mutate(&claim.owner, |stats| {
+				stats.active = stats.active.saturating_sub(1)
+			});
+			for owner in SharedOwners::<T>::take(namespace, proof).unwrap_or_default().iter() {
+				Self::remove_from_claims_of(owner, namespace, proof);
+			}
+			RevokeApprovals::<T>::remove(namespace, proof);
+			Self::unlink_supersession(namespace, proof);
+			if let Some(challenge) = Challenges::<T>::take(namespace, proof) {
+				T::Currency::unreserve(&challenge.challenger, challenge.bond);
+			}
+			ProofMetadata::<T>::remove(namespace, proof);
+			MetadataVersion::<T>::remove(namespace, proof);
+			MetadataEditors::<T>::remove(namespace, proof);
+			TransferAllowlist::<T>::remove(namespace, proof);
+			Self::clear_tags(namespace, proof);
+			ProofTimestamps::<T>::remove(namespace, proof);
+			ProofHashAlgo::<T>::remove(namespace, proof);
+			ContentTypes::<T>::remove(namespace, proof);
+			Self::clear_attributes(namespace, proof);
+			if let Some(priority) = ProofPriority::<T>::take(namespace, proof) {
+				PriorityIndex::<T>::remove(priority, (namespace.clone(), proof.clone()));
+			}
+			TransferHistory::<T>::remove(namespace, proof);
+			LastTransferAt::<T>::remove(namespace, proof);
+			Approvals::<T>::remove(namespace, proof);
+			PendingTransfers::<T>::remove(namespace, proof);
+			Self::release_transfer_bond(namespace, proof);
+			Listings::<T>::remove(namespace, proof);
+			Soulbound::<T>::remove(namespace, proof);
+			Pinned::<T>::remove(namespace, proof);
+			PrivateProofs::<T>::remove(namespace, proof);
+			ViewPermissions::<T>::remove(namespace, proof);
+			LockedProofs::<T>::remove(namespace, proof);
+			if let Some(expires_at) = ProofExpiry::<T>::take(namespace, proof) {
+				Self::dequeue_expiry(expires_at, namespace, proof);
+			}
+			ExpiryWarned::<T>::remove(namespace, proof);
+			if let Some(id) = ProofIds::<T>::take(namespace, proof) {
+				ClaimIdToProof::<T>::remove(id);
+			}
+			Some(claim)
+		}
+
+		/// 记录(或修改)存证的优先级, 并更新优先级索引.
+		fn set_priority(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			priority: u8,
+		) {
+			if let Some(old) = ProofPriority::<T>::get(namespace, proof) {
+				PriorityIndex::<T>::remove(old, (namespace.clone(), proof.clone()));
+			}
+			ProofPriority::<T>::insert(namespace, proof, priority);
+			PriorityIndex::<T>::insert(priority, (namespace.clone(), proof.clone()), ());
+		}
+
+		/// 移除一个优先级低于 `priority` 的存证并发出 `ClaimEvicted` 事件, 优先级最低的存证优先.
+		/// 被锁定、被固定、正在被质疑或由多个账户共有的存证不会被移除. 最多检查
+		/// `MAX_EVICTION_CANDIDATES` 个存证, 其中没有可以移除的存证时返回 `false`.
+		fn evict_below(priority: u8) -> bool {
+			let mut remaining = MAX_EVICTION_CANDIDATES as usize;
+			for lower in 0..priority {
+				if remaining == 0 {
+					break
+				}
+				// 先取出键再修改, 避免在遍历的同时修改索引
+				let candidates: Vec<_> =
+					PriorityIndex::<T>::iter_key_prefix(lower).take(remaining).collect();
+				remaining -= candidates.len();
+				for (namespace, proof) in candidates {
+					if !Self::is_evictable(&namespace, &proof) {
+						continue
+					}
+					// 移除存证时会同时移除其优先级索引
+					if let Some(claim) = Self::remove_claim(&namespace, &proof) {
+						Self::deposit_event(Event::ClaimEvicted(claim.owner, namespace, proof));
+						return true
+					}
+					PriorityIndex::<T>::remove(lower, (namespace, proof));
+				}
+			}
+			false
+		}
+
+		/// 存证是否可以为优先级更高的存证让出空间. 被锁定、被固定或正在被质疑的存证需要先处理,
+		/// 共有存证的移除需要全部持有者同意, 这些存证都不能被移除.
+		fn is_evictable(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> bool {
+			!LockedProofs::<T>::contains_key(namespace, proof) &&
+				!Pinned::<T>::contains_key(namespace, proof) &&
+				!Challenges::<T>::contains_key(namespace, proof) &&
+				!SharedOwners::<T>::contains_key(namespace, proof)
+		}
+
+		/// 退还等待批准的存证在提议时保留的押金, 没有押金时不做任何操作.
+		fn release_pending_deposit(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) {
+			if let Some((who, deposit)) = PendingClaimDeposits::<T>::take(namespace, proof) {
+				T::Currency::unreserve(&who, deposit);
+			}
+		}
+
+		/// 退还存证的待接收转移保留的保证金, 没有保证金时不做任何操作.
+		fn release_transfer_bond(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) {
+			if let Some((who, bond)) = TransferBonds::<T>::take(namespace, proof) {
+				T::Currency::unreserve(&who, bond);
+				Self::deposit_event(Event::TransferBondReleased(
+					who,
+					namespace.clone(),
+					proof.clone(),
+					bond,
+				));
+			}
+		}
+
+		/// 从 `ExpiryCursor` 开始按区块顺序清理已经过期的存证, 最多清理 `max_claims` 个存证、
+		/// 检查 `max_blocks` 个区块, 并且清理和检查所需的权重不超过 `max_weight`.
+		/// 返回清理的存证数量和检查的区块数量.
+		fn sweep_expired(max_claims: u32, max_blocks: u32, max_weight: Weight) -> (u32, u32) {
+			let per_claim =
+				T::WeightInfo::clean_expired(1).saturating_sub(T::WeightInfo::clean_expired(0));
+			let per_block = T::DbWeight::get().reads(1);
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut block = ExpiryCursor::<T>::get();
+			let mut cleaned: u32 = 0;
+			let mut visited: u32 = 0;
+			let mut used: Weight = 0;
+
+			// 从上一次停下的区块开始, 按区块顺序清理过期队列
+			while block <= now &&
+				cleaned < max_claims &&
+				visited < max_blocks &&
+				used.saturating_add(per_block) <= max_weight
+			{
+				visited += 1;
+				used = used.saturating_add(per_block);
+				let affordable = (max_weight.saturating_sub(used) / per_claim.max(1))
+					.min((max_claims - cleaned) as Weight) as usize;
+
+				let mut expired = ExpiryQueue::<T>::take(block).into_inner();
+				let rest = expired.split_off(affordable.min(expired.len()));
+				cleaned += expired.len() as u32;
+				used = used.saturating_add(per_claim.saturating_mul(expired.len() as Weight));
+				for (namespace, proof) in expired {
+					Self::expire_claim(&namespace, &proof);
+				}
+
+				// 该区块还有没有清理的存证时放回队列, 下一次从该区块继续
+				if !rest.is_empty() {
+					let rest: BoundedVec<_, T::MaxExpiredPerBlock> =
+						rest.try_into().expect("rest is shorter than the original queue; qed");
+					ExpiryQueue::<T>::insert(block, rest);
+					break
+				}
+				block = block.saturating_add(One::one());
+			}
+			ExpiryCursor::<T>::put(block);
+
+			(cleaned, visited)
+		}
+
+		/// 返回长度为 `BloomBytes` 且所有位都未设置的布隆过滤器.
+		fn empty_bloom() -> BoundedVec<u8, T::BloomBytes> {
+			sp_std::vec![0u8; T::BloomBytes::get() as usize]
+				.try_into()
+				.expect("filter has exactly BloomBytes bytes; qed")
+		}
+
+		/// 返回存证在长度为 `len` 字节的布隆过滤器中对应的位.
+		fn bloom_positions(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			len: usize,
+		) -> [usize; BLOOM_HASHES] {
+			let hash = (namespace, proof).using_encoded(frame_support::sp_io::hashing::blake2_256);
+			let mut positions = [0usize; BLOOM_HASHES];
+			for (i, position) in positions.iter_mut().enumerate() {
+				let word = u32::from_le_bytes([
+					hash[4 * i],
+					hash[4 * i + 1],
+					hash[4 * i + 2],
+					hash[4 * i + 3],
+				]);
+				*position = word as usize % (len * 8);
+			}
+			positions
+		}
+
+		/// 在布隆过滤器中设置存证对应的位, 过滤器为空时不做任何修改.
+		fn bloom_insert(
+			filter: &mut BoundedVec<u8, T::BloomBytes>,
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) {
+			if filter.is_empty() {
+				return
+			}
+			for bit in Self::bloom_positions(namespace, proof, filter.len()) {
+				if let Some(byte) = filter.get_mut(bit / 8) {
+					*byte |= 1 << (bit % 8);
+				}
+			}
+		}
+
+		/// 将新创建的存证加入当前的布隆过滤器和正在重建的布隆过滤器.
+		fn note_in_bloom(namespace: &NamespaceOf<T>, proof: &BoundedVec<u8, T::MaxBytesInHash>) {
+			if T::BloomBytes::get() == 0 {
+				return
+			}
+			ProofBloom::<T>::mutate(|filter| Self::bloom_insert(filter, namespace, proof));
+			BloomRebuild::<T>::mutate(|rebuild| {
+				if let Some((filter, _)) = rebuild {
+					Self::bloom_insert(filter, namespace, proof);
+				}
+			});
+		}
+
+		/// 移除过期的存证, 记录过期时的持有者并发出 `ClaimExpired` 事件.
+		/// 被固定的存证保留在链上, 取消固定时重新加入过期队列.
+		fn expire_claim(namespace: &NamespaceOf<T>, proof: &BoundedVec<u8, T::MaxBytesInHash>) {
+			if Pinned::<T>::contains_key(namespace, proof) {
+				return
+			}
+			if let Some(claim) = Self::remove_claim(namespace, proof) {
+				Self::note_expired(namespace, proof, &claim.owner);
+				Self::deposit_event(Event::ClaimExpired(
+					claim.owner,
+					namespace.clone(),
+					proof.clone(),
+				));
+			}
+		}
+
+		/// 在 `ExpiredProofs` 中记录过期的存证, 记录已达到 `MaxExpiredRecords` 时先移除最早的记录.
+		fn note_expired(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+			owner: &T::AccountId,
+		) {
+			let max = T::MaxExpiredRecords::get() as u64;
+			if max == 0 {
+				return
+			}
+
+			// 上限被调低之后每次多移除一条记录, 使记录的数量逐渐回到上限以内
+			let (mut head, tail) = ExpiredRecordRange::<T>::get();
+			let mut pruned = 0;
+			while tail.saturating_sub(head) >= max && pruned < 2 {
+				// 序号不一致说明该记录已被重新声明, 或存证再次过期后有了更新的记录
+				if let Some((oldest_ns, oldest)) = ExpiredRecordQueue::<T>::take(head) {
+					if ExpiredRecordIndex::<T>::get(&oldest_ns, &oldest) == Some(head) {
+						ExpiredRecordIndex::<T>::remove(&oldest_ns, &oldest);
+						ExpiredProofs::<T>::remove(&oldest_ns, &oldest);
+					}
+				}
+				head = head.saturating_add(1);
+				pruned += 1;
+			}
+
+			ExpiredRecordQueue::<T>::insert(tail, (namespace.clone(), proof.clone()));
+			ExpiredRecordIndex::<T>::insert(namespace, proof, tail);
+			ExpiredProofs::<T>::insert(namespace, proof, owner);
+			ExpiredRecordRange::<T>::put((head, tail.saturating_add(1)));
+		}
+
+		/// 将存证及其所有关联数据从 `old` 移动到同一命名空间下的 `new`.
+		/// 调用者需要事先验证 `new` 尚未被声明.
+		#[transactional]
+		fn rekey_claim(
+			namespace: &NamespaceOf<T>,
+			old: &BoundedVec<u8, T::MaxBytesInHash>,
+			new: &BoundedVec<u8, T::MaxBytesInHash>,
+			claim: &ClaimInfoOf<T>,
+		) -> DispatchResult {
+			// 替换所有持有者存证列表中的条目, 列表长度不变
+			let shared = SharedOwners::<T>::take(namespace, old);
+			let owners = match &shared {
+				Some(owners) => owners.to_vec(),
+				None => sp_std::vec![claim.owner.clone()],
+			};
+			for owner in owners.iter() {
+				Self::remove_from_claims_of(owner, namespace, old);
+				ClaimsOf::<T>::try_mutate(owner, |claims| {
+					claims
+						.try_push((namespace.clone(), new.clone()))
+						.map_err(|_| Error::<T>::ExceededMaxClaims)
+				})?;
+			}
+			if let Some(owners) = shared {
+				SharedOwners::<T>::insert(namespace, new, owners);
+			}
+			if RevokeApprovals::<T>::contains_key(namespace, old) {
+				RevokeApprovals::<T>::insert(
+					namespace,
+					new,
+					RevokeApprovals::<T>::take(namespace, old),
+				);
+			}
+
+			// 替换过期队列中的条目, 队列长度不变
+			if let Some(expires_at) = ProofExpiry::<T>::take(namespace, old) {
+				Self::dequeue_expiry(expires_at, namespace, old);
+				Self::enqueue_expiry(expires_at, namespace, new)?;
+				ProofExpiry::<T>::insert(namespace, new, expires_at);
+				if ExpiryWarned::<T>::take(namespace, old).is_some() {
+					ExpiryWarned::<T>::insert(namespace, new, ());
+				}
+			}
+
+			Proofs::<T>::remove(namespace, old);
+			Proofs::<T>::insert(namespace, new, claim);
+			if let Some(metadata) = ProofMetadata::<T>::take(namespace, old) {
+				ProofMetadata::<T>::insert(namespace, new, metadata);
+			}
+			if MetadataVersion::<T>::contains_key(namespace, old) {
+				MetadataVersion::<T>::insert(
+					namespace,
+					new,
+					MetadataVersion::<T>::take(namespace, old),
+				);
+			}
+			let tags = ProofTags::<T>::take(namespace, old);
+			for tag in tags.iter() {
+				let key = (namespace.clone(), tag.clone());
+				TagIndex::<T>::remove(&key, old);
+				TagIndex::<T>::insert(&key, new, ());
+			}
+			if !tags.is_empty() {
+				ProofTags::<T>::insert(namespace, new, tags);
+			}
+			if let Some(timestamp) = ProofTimestamps::<T>::take(namespace, old) {
+				ProofTimestamps::<T>::insert(namespace, new, timestamp);
+			}
+			let count = AttributeCount::<T>::take(namespace, old);
+			if count > 0 {
+				let new_key = (namespace.clone(), new.clone());
+				for (key, value) in Attributes::<T>::drain_prefix((namespace.clone(), old.clone()))
+				{
+					Attributes::<T>::insert(&new_key, key, value);
+				}
+				AttributeCount::<T>::insert(namespace, new, count);
+			}
+			if TransferHistory::<T>::contains_key(namespace, old) {
+				TransferHistory::<T>::insert(
+					namespace,
+					new,
+					TransferHistory::<T>::take(namespace, old),
+				);
+			}
+			if let Some(challenge) = Challenges::<T>::take(namespace, old) {
+				Challenges::<T>::insert(namespace, new, challenge);
+			}
+			// 更新后的存证保留原来的编号
+			if let Some(id) = ProofIds::<T>::take(namespace, old) {
+				ClaimIdToProof::<T>::insert(id, (namespace.clone(), new.clone()));
+				ProofIds::<T>::insert(namespace, new, id);
+			}
+			if let Some(previous) = Supersedes::<T>::take(namespace, old) {
+				SupersededBy::<T>::insert(namespace, &previous, new);
+				Supersedes::<T>::insert(namespace, new, previous);
+			}
+			if let Some(next) = SupersededBy::<T>::take(namespace, old) {
+				Supersedes::<T>::insert(namespace, &next, new);
+				SupersededBy::<T>::insert(namespace, new, next);
+			}
+			if let Some(algo) = ProofHashAlgo::<T>::take(namespace, old) {
+				ProofHashAlgo::<T>::insert(namespace, new, algo);
+			}
+			if let Some(content_type) = ContentTypes::<T>::take(namespace, old) {
+				ContentTypes::<T>::insert(namespace, new, content_type);
+			}
+			if let Some(priority) = ProofPriority::<T>::take(namespace, old) {
+				PriorityIndex::<T>::remove(priority, (namespace.clone(), old.clone()));
+				Self::set_priority(namespace, new, priority);
+			}
+			if TransferAllowlist::<T>::contains_key(namespace, old) {
+				TransferAllowlist::<T>::insert(
+					namespace,
+					new,
+					TransferAllowlist::<T>::take(namespace, old),
+				);
+			}
+			if MetadataEditors::<T>::contains_key(namespace, old) {
+				MetadataEditors::<T>::insert(
+					namespace,
+					new,
+					MetadataEditors::<T>::take(namespace, old),
+				);
+			}
+			if let Some(last) = LastTransferAt::<T>::take(namespace, old) {
+				LastTransferAt::<T>::insert(namespace, new, last);
+			}
+			if let Some(spender) = Approvals::<T>::take(namespace, old) {
+				Approvals::<T>::insert(namespace, new, spender);
+			}
+			if let Some(dest) = PendingTransfers::<T>::take(namespace, old) {
+				PendingTransfers::<T>::insert(namespace, new, dest);
+			}
+			if let Some(bond) = TransferBonds::<T>::take(namespace, old) {
+				TransferBonds::<T>::insert(namespace, new, bond);
+			}
+			if let Some(listing) = Listings::<T>::take(namespace, old) {
+				Listings::<T>::insert(namespace, new, listing);
+			}
+			if Soulbound::<T>::take(namespace, old).is_some() {
+				Soulbound::<T>::insert(namespace, new, ());
+			}
+			if Pinned::<T>::take(namespace, old).is_some() {
+				Pinned::<T>::insert(namespace, new, ());
+			}
+			if PrivateProofs::<T>::take(namespace, old).is_some() {
+				PrivateProofs::<T>::insert(namespace, new, ());
+			}
+			if ViewPermissions::<T>::contains_key(namespace, old) {
+				ViewPermissions::<T>::insert(
+					namespace,
+					new,
+					ViewPermissions::<T>::take(namespace, old),
+				);
+			}
+			// 旧的存证无法从布隆过滤器中移除, 新的存证必须加入, 否则会被误判为不存在
+			Self::note_in_bloom(namespace, new);
+			Ok(())
+		}
+
+		/// 为新创建的存证分配下一个编号.
+		pub(crate) fn assign_claim_id(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> u64 {
+			let id = NextClaimId::<T>::mutate(|next| {
+				let id = *next;
+				*next = next.saturating_add(1);
+				id
+			});
+			ClaimIdToProof::<T>::insert(id, (namespace.clone(), proof.clone()));
+			ProofIds::<T>::insert(namespace, proof, id);
+			id
+		}
+
+		/// 检查存储的不变量, 任何一项不满足时返回描述该问题的错误. 可以在任意操作序列之后调用,
+		/// 例如在迁移之后或在随机生成操作序列的测试中:
+		/// - 存证的数量与 `ProofCount` 一致
+		/// - 存证和撤销记录保留的押金之和与 `TotalReservedDeposits` 一致
+		/// - 每个存证都记录在其持有者的 `ClaimsOf` 中, `ClaimsOf` 中的存证都由该账户持有
+		/// - 有效的存证没有被记录为已撤销
+		/// - 共有存证的第一个持有者与 `Proofs` 中记录的持有者一致
+		/// - 存证的编号双向一致, 且都小于 `NextClaimId`
+		/// - 每条过期记录都在 `ExpiredRecordQueue` 中记录的范围内
+		#[cfg(any(feature = "std", feature = "try-runtime"))]
+		pub fn check_invariants() -> Result<(), &'static str> {
+			let mut count: u32 = 0;
+			let mut deposits: BalanceOf<T> = Zero::zero();
+			for (namespace, proof, claim) in Proofs::<T>::iter() {
+				count += 1;
+				deposits = deposits.saturating_add(claim.deposit);
+				ensure!(
+					ClaimsOf::<T>::get(&claim.owner)
+						.iter()
+						.any(|(ns, p)| *ns == namespace && *p == proof),
+					"proof is missing from its owner's ClaimsOf"
+				);
+				ensure!(
+					!RevokedProofs::<T>::contains_key(&namespace, &proof),
+					"proof is both active and revoked"
+				);
+				if let Some(owners) = SharedOwners::<T>::get(&namespace, &proof) {
+					ensure!(
+						owners.first() == Some(&claim.owner),
+						"first shared owner does not match the recorded owner"
+					);
+				}
+				if let Some(id) = ProofIds::<T>::get(&namespace, &proof) {
+					ensure!(
+						ClaimIdToProof::<T>::get(id) == Some((namespace.clone(), proof.clone())),
+						"claim id does not map back to its proof"
+					);
+				}
+			}
+			ensure!(count == ProofCount::<T>::get(), "ProofCount does not match stored proofs");
+			for (namespace, proof, (_, deposit)) in TombstoneDeposits::<T>::iter() {
+				ensure!(
+					RevokedProofs::<T>::contains_key(&namespace, &proof),
+					"tombstone deposit without a revoked proof"
+				);
+				deposits = deposits.saturating_add(deposit);
+			}
+			ensure!(
+				deposits == TotalReservedDeposits::<T>::get(),
+				"TotalReservedDeposits does not match stored deposits"
+			);
+
+			for (who, claims) in ClaimsOf::<T>::iter() {
+				for (namespace, proof) in claims.iter() {
+					ensure!(
+						Self::is_owner(namespace, proof, &who),
+						"ClaimsOf lists a proof the account does not own"
+					);
+				}
+			}
+
+			let next_id = NextClaimId::<T>::get();
+			for (id, (namespace, proof)) in ClaimIdToProof::<T>::iter() {
+				ensure!(id < next_id, "claim id was not allocated from NextClaimId");
+				ensure!(
+					ProofIds::<T>::get(&namespace, &proof) == Some(id),
+					"proof does not map back to its claim id"
+				);
+			}
+
+			let (head, tail) = ExpiredRecordRange::<T>::get();
+			for (namespace, proof) in ExpiredProofs::<T>::iter_keys() {
+				let index = ExpiredRecordIndex::<T>::get(&namespace, &proof)
+					.ok_or("expired record is missing from ExpiredRecordIndex")?;
+				ensure!(head <= index && index < tail, "expired record is outside the queue");
+				ensure!(
+					ExpiredRecordQueue::<T>::get(index) == Some((namespace, proof)),
+					"ExpiredRecordQueue does not map back to the expired record"
+				);
+			}
+			Ok(())
+		}
+
+		/// 返回编号对应的命名空间和存证, 没有该编号的存证时返回 `NoSuchClaimId` 错误.
+		fn proof_of_id(
+			id: u64,
+		) -> Result<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>), DispatchErrorWithPostInfo>
+		{
+			ClaimIdToProof::<T>::get(id)
+				.ok_or_else(|| Error::<T>::NoSuchClaimId.with_weight(T::DbWeight::get().reads(1)))
+		}
+
+		/// 移除存证与其他存证之间的取代关系. 被移除的存证所取代的旧版本不再被标记为已被取代,
+		/// 取代了被移除存证的新版本仍然记录它取代的存证, 以保留版本链.
+		fn unlink_supersession(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) {
+			if let Some(previous) = Supersedes::<T>::take(namespace, proof) {
+				SupersededBy::<T>::remove(namespace, previous);
+			}
+			SupersededBy::<T>::remove(namespace, proof);
+		}
+
+		/// 为将在 `n + ExpiryWarningBlocks` 过期且尚未被提醒的存证发出 `ClaimExpiringSoon` 事件,
+		/// 最多 `MaxExpiryWarningsPerBlock` 个, 返回发出的事件数量.
+		/// 有效期不超过 `ExpiryWarningBlocks` 的存证和被固定的存证不会被提醒.
+		fn warn_expiring(n: T::BlockNumber) -> u32 {
+			let window = T::ExpiryWarningBlocks::get();
+			if window.is_zero() {
+				return 0
+			}
+			let expires_at = n.saturating_add(window);
+
+			let mut warned: u32 = 0;
+			for (namespace, proof) in ExpiryQueue::<T>::get(expires_at) {
+				if warned >= T::MaxExpiryWarningsPerBlock::get() {
+					break
+				}
+				if ExpiryWarned::<T>::contains_key(&namespace, &proof) ||
+					Pinned::<T>::contains_key(&namespace, &proof)
+				{
+					continue
+				}
+				if let Some(claim) = Proofs::<T>::get(&namespace, &proof) {
+					ExpiryWarned::<T>::insert(&namespace, &proof, ());
+					Self::deposit_event(Event::ClaimExpiringSoon(
+						claim.owner,
+						namespace,
+						proof,
+						expires_at,
+					));
+					warned += 1;
+				}
+			}
+			warned
+		}
+
+		/// 移除存证的所有标签及其索引, 返回被移除的标签数量.
+		fn clear_tags(
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> u32 {
+			let tags = ProofTags::<T>::take(namespace, proof);
+			for tag in tags.iter() {
+				TagIndex::<T>::remove((namespace.clone(), tag.clone()), proof);
+			}
+			tags.len() as u32
+		}
+
+		/// 清除存证的所有属性, 属性数量受 `MaxAttributesPerProof` 限制.
+		fn clear_attributes(namespace: &NamespaceOf<T>, proof: &BoundedVec<u8, T::MaxBytesInHash>) {
+			if AttributeCount::<T>::take(namespace, proof) > 0 {
+				Attributes::<T>::drain_prefix((namespace.clone(), proof.clone())).for_each(drop);
+			}
+		}
+
+		/// 将存证从账户持有的存证列表中移除, 列表为空时删除该列表.
+		fn remove_from_claims_of(
+			who: &T::AccountId,
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) {
+			ClaimsOf::<T>::mutate_exists(who, |maybe_claims| {
+				if let Some(claims) = maybe_claims {
+					claims.retain(|(ns, p)| ns != namespace || p != proof);
+					if claims.is_empty() {
+						*maybe_claims = None;
+					}
+				}
+			});
+		}
+
+		/// 在 `expires_at` 调度存证的过期, 无法调度时将存证加入该区块的过期队列.
+		fn enqueue_expiry(
+			expires_at: T::BlockNumber,
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) -> DispatchResult {
+			if T::ExpiryScheduler::schedule(namespace, proof, expires_at) {
+				return Ok(())
+			}
+			ExpiryQueue::<T>::try_mutate(expires_at, |queue| {
+				queue
+					.try_push((namespace.clone(), proof.clone()))
+					.map_err(|_| Error::<T>::TooManyExpiries)
+			})?;
+			Ok(())
+		}
+
+		/// 取消存证的调度任务并将存证从指定区块的过期队列中移除, 队列为空时删除该队列.
+		fn dequeue_expiry(
+			expires_at: T::BlockNumber,
+			namespace: &NamespaceOf<T>,
+			proof: &BoundedVec<u8, T::MaxBytesInHash>,
+		) {
+			T::ExpiryScheduler::cancel(namespace, proof);
+			ExpiryQueue::<T>::mutate_exists(expires_at, |maybe_queue| {
+				if let Some(queue) = maybe_queue {
+					queue.retain(|(ns, p)| ns != namespace || p != proof);
+					if queue.is_empty() {
+						*maybe_queue = None;
+					}
+				}
+			});
+		}
+	}
 }
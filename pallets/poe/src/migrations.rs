@@ -0,0 +1,477 @@
+//! Storage migrations for pallet-poe
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	storage::migration::{get_storage_value, put_storage_value, storage_iter, storage_key_iter},
+	traits::{GetStorageVersion, PalletInfoAccess, StorageVersion},
+	Blake2_128Concat, ReversibleStorageHasher, StorageHasher, Twox64Concat,
+};
+use sp_std::prelude::*;
+
+fn pallet_name<T: Config>() -> &'static [u8] {
+	<Pallet<T> as PalletInfoAccess>::name().as_bytes()
+}
+
+/// 依次执行所有尚未执行的迁移.
+pub fn migrate<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+	if on_chain < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+	if on_chain < 3 {
+		weight = weight.saturating_add(v3::migrate::<T>());
+	}
+	if on_chain < 4 {
+		weight = weight.saturating_add(v4::migrate::<T>());
+	}
+	if on_chain < 5 {
+		weight = weight.saturating_add(v5::migrate::<T>());
+	}
+	if on_chain >= 5 {
+		log::info!(
+			target: "runtime::poe",
+			"skipping migrations: on-chain storage version is {:?}",
+			on_chain,
+		);
+	}
+
+	weight
+}
+
+/// 记录迁移前可以被保留的存证数量.
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<(), &'static str> {
+	use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let expected = if on_chain >= 2 {
+		Proofs::<T>::iter().count() as u32
+	} else if on_chain == 1 {
+		frame_support::storage::migration::storage_iter::<ClaimInfoOf<T>>(
+			pallet_name::<T>(),
+			v1::PROOFS,
+		)
+		.count() as u32
+	} else {
+		storage_key_iter::<Vec<u8>, v1::OldClaim<T>, Blake2_128Concat>(
+			pallet_name::<T>(),
+			v1::PROOFS,
+		)
+		.filter(|(key, _)| key.len() as u32 <= T::MaxBytesInHash::get())
+		.count() as u32
+	};
+	Pallet::<T>::set_temp_storage(expected, "expected_proofs");
+	Ok(())
+}
+
+/// 验证迁移后的存证数量与迁移前记录的数量一致.
+#[cfg(feature = "try-runtime")]
+pub fn post_upgrade<T: Config>() -> Result<(), &'static str> {
+	use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+	ensure!(Pallet::<T>::on_chain_storage_version() >= 5, "storage version was not bumped to 5");
+	let expected: u32 = Pallet::<T>::get_temp_storage("expected_proofs")
+		.ok_or("pre_upgrade did not record the expected proof count")?;
+	ensure!(
+		Proofs::<T>::iter().count() as u32 == expected,
+		"number of proofs changed during migration"
+	);
+	ensure!(ProofCount::<T>::get() == expected, "ProofCount does not match stored proofs");
+	ensure!(
+		Proofs::<T>::iter_keys()
+			.all(|(namespace, proof)| ProofIds::<T>::contains_key(&namespace, &proof)),
+		"some proofs were not assigned an id"
+	);
+	Pallet::<T>::check_invariants()
+}
+
+/// 从以 `Vec<u8>` 为键、`(AccountId, BlockNumber)` 为值的旧版 `Proofs` 迁移到版本 1.
+///
+/// 旧版的存证键以 `BoundedVec` 重新写入, 超出 `MaxBytesInHash` 的存证会被丢弃并记录日志.
+/// 迁移后的存证没有押金和有效期, 与创世区块中预置的存证相同.
+///
+/// 当前的存储项已经是更新版本的布局, 因此这里直接写入版本 1 的原始存储.
+pub mod v1 {
+	use super::*;
+
+	/// `Proofs` 的存储项名称
+	pub(super) const PROOFS: &[u8] = b"Proofs";
+	/// `ClaimsOf` 的存储项名称
+	const CLAIMS_OF: &[u8] = b"ClaimsOf";
+
+	pub(super) type OldClaim<T> =
+		(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber);
+
+	/// 执行迁移. 链上的存储版本已经不低于 1 时不做任何修改, 因此可以安全地重复执行.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain = Pallet::<T>::on_chain_storage_version();
+		if on_chain >= 1 {
+			log::info!(
+				target: "runtime::poe",
+				"skipping v1 migration: on-chain storage version is {:?}",
+				on_chain,
+			);
+			return T::DbWeight::get().reads(1)
+		}
+
+		// 先取出全部旧版存证再重新写入, 避免在迭代同一前缀时修改存储
+		let old: Vec<(Vec<u8>, OldClaim<T>)> =
+			storage_key_iter::<Vec<u8>, OldClaim<T>, Blake2_128Concat>(pallet_name::<T>(), PROOFS)
+				.drain()
+				.collect();
+
+		let count = old.len() as u64;
+		let mut migrated: u64 = 0;
+		for (key, (owner, block_number)) in old {
+			if key.len() as u32 > T::MaxBytesInHash::get() {
+				log::warn!(
+					target: "runtime::poe",
+					"dropping proof of {} bytes exceeding MaxBytesInHash: {:?}",
+					key.len(),
+					key,
+				);
+				continue
+			}
+			let proof = match BoundedVec::<u8, T::MaxBytesInHash>::try_from(key) {
+				Ok(proof) => proof,
+				Err(_) => continue,
+			};
+
+			// 版本 1 中 `ClaimsOf` 只记录存证本身
+			let owner_key = Blake2_128Concat::hash(&owner.encode());
+			let mut claims: Vec<BoundedVec<u8, T::MaxBytesInHash>> =
+				get_storage_value(pallet_name::<T>(), CLAIMS_OF, &owner_key).unwrap_or_default();
+			if (claims.len() as u32) < T::MaxClaimsPerAccount::get() {
+				claims.push(proof.clone());
+				put_storage_value(pallet_name::<T>(), CLAIMS_OF, &owner_key, claims);
+			} else {
+				log::warn!(
+					target: "runtime::poe",
+					"account exceeds MaxClaimsPerAccount, proof not indexed in ClaimsOf",
+				);
+			}
+
+			let claim: ClaimInfoOf<T> = ClaimInfo {
+				owner: owner.clone(),
+				creator: owner,
+				block_number,
+				deposit: Zero::zero(),
+			};
+			put_storage_value(
+				pallet_name::<T>(),
+				PROOFS,
+				&Blake2_128Concat::hash(&proof.encode()),
+				claim,
+			);
+			migrated += 1;
+		}
+
+		ProofCount::<T>::put(migrated as u32);
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(
+			target: "runtime::poe",
+			"migrated {} of {} proofs to storage version 1",
+			migrated,
+			count,
+		);
+
+		T::DbWeight::get().reads_writes(1 + 2 * count, 2 + count + 2 * migrated)
+	}
+}
+
+/// 将所有以存证为键的存储项迁移到以 `(namespace, proof)` 为键的版本 2.
+///
+/// 已有的存证全部被移动到默认的(空的)命名空间下, 其他数据保持不变.
+pub mod v2 {
+	use super::*;
+
+	type ProofOf<T> = BoundedVec<u8, <T as Config>::MaxBytesInHash>;
+
+	/// 将以存证为键的存储项 `item` 中的所有条目移动到默认命名空间下, 存证键使用 `H` 计算,
+	/// 返回移动的条目数量.
+	fn move_into_default_namespace<T, V, H>(item: &[u8]) -> u64
+	where
+		T: Config,
+		V: Decode + Encode,
+		H: StorageHasher<Output = Vec<u8>>,
+	{
+		let entries: Vec<(ProofOf<T>, V)> =
+			storage_key_iter::<ProofOf<T>, V, Blake2_128Concat>(pallet_name::<T>(), item)
+				.drain()
+				.collect();
+
+		let namespace_key = Blake2_128Concat::hash(&NamespaceOf::<T>::default().encode());
+		let count = entries.len() as u64;
+		for (proof, value) in entries {
+			let key = [namespace_key.clone(), H::hash(&proof.encode())].concat();
+			put_storage_value(pallet_name::<T>(), item, &key, value);
+		}
+		count
+	}
+
+	/// 将存证列表中的每个存证转换为默认命名空间下的存证, 超出上限时返回 `None`.
+	fn into_default_namespace<T: Config, S: Get<u32>>(
+		proofs: Vec<ProofOf<T>>,
+	) -> Option<BoundedVec<(NamespaceOf<T>, ProofOf<T>), S>> {
+		proofs
+			.into_iter()
+			.map(|proof| (NamespaceOf::<T>::default(), proof))
+			.collect::<Vec<_>>()
+			.try_into()
+			.ok()
+	}
+
+	/// 执行迁移. 链上的存储版本已经不低于 2 时不做任何修改, 因此可以安全地重复执行.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain = Pallet::<T>::on_chain_storage_version();
+		if on_chain >= 2 {
+			log::info!(
+				target: "runtime::poe",
+				"skipping v2 migration: on-chain storage version is {:?}",
+				on_chain,
+			);
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut moved = move_into_default_namespace::<T, ClaimInfoOf<T>, ProofKeyHasher>(b"Proofs");
+		moved += move_into_default_namespace::<
+			T,
+			BoundedVec<u8, T::MaxMetadataLength>,
+			Blake2_128Concat,
+		>(b"ProofMetadata");
+		moved += move_into_default_namespace::<T, T::BlockNumber, Blake2_128Concat>(b"ProofExpiry");
+		moved += move_into_default_namespace::<T, T::AccountId, Blake2_128Concat>(b"Approvals");
+		moved +=
+			move_into_default_namespace::<T, T::AccountId, Blake2_128Concat>(b"PendingTransfers");
+		moved += move_into_default_namespace::<T, (), Blake2_128Concat>(b"Soulbound");
+		moved += move_into_default_namespace::<T, u64, Blake2_128Concat>(b"ProofTimestamps");
+		moved += move_into_default_namespace::<
+			T,
+			BoundedVec<(T::AccountId, T::BlockNumber), T::MaxHistoryLength>,
+			Blake2_128Concat,
+		>(b"TransferHistory");
+
+		let claims: Vec<(T::AccountId, Vec<ProofOf<T>>)> = storage_key_iter::<
+			T::AccountId,
+			Vec<ProofOf<T>>,
+			Blake2_128Concat,
+		>(pallet_name::<T>(), b"ClaimsOf")
+		.drain()
+		.collect();
+		moved += claims.len() as u64;
+		for (who, proofs) in claims {
+			match into_default_namespace::<T, T::MaxClaimsPerAccount>(proofs) {
+				Some(proofs) => ClaimsOf::<T>::insert(&who, proofs),
+				None => log::warn!(
+					target: "runtime::poe",
+					"account exceeds MaxClaimsPerAccount, proofs not indexed in ClaimsOf",
+				),
+			}
+		}
+
+		let queues: Vec<(T::BlockNumber, Vec<ProofOf<T>>)> = storage_key_iter::<
+			T::BlockNumber,
+			Vec<ProofOf<T>>,
+			Twox64Concat,
+		>(pallet_name::<T>(), b"ExpiryQueue")
+		.drain()
+		.collect();
+		moved += queues.len() as u64;
+		for (expires_at, proofs) in queues {
+			match into_default_namespace::<T, T::MaxExpiredPerBlock>(proofs) {
+				Some(proofs) => ExpiryQueue::<T>::insert(expires_at, proofs),
+				None => log::warn!(
+					target: "runtime::poe",
+					"expiry queue at {:?} exceeds MaxExpiredPerBlock, proofs will not expire",
+					expires_at,
+				),
+			}
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		log::info!(
+			target: "runtime::poe",
+			"moved {} entries into the default namespace for storage version 2",
+			moved,
+		);
+
+		T::DbWeight::get().reads_writes(1 + moved, 1 + 2 * moved)
+	}
+}
+
+/// 为版本 3 之前创建的存证按存储顺序分配编号, 之后创建的存证在创建时分配编号.
+pub mod v3 {
+	use super::*;
+
+	/// 执行迁移. 链上的存储版本已经不低于 3 时不做任何修改, 因此可以安全地重复执行.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain = Pallet::<T>::on_chain_storage_version();
+		if on_chain >= 3 {
+			log::info!(
+				target: "runtime::poe",
+				"skipping v3 migration: on-chain storage version is {:?}",
+				on_chain,
+			);
+			return T::DbWeight::get().reads(1)
+		}
+
+		// 先取出全部存证再分配编号, 避免在迭代时修改存储
+		let proofs: Vec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)> =
+			Proofs::<T>::iter_keys().collect();
+		let count = proofs.len() as u64;
+		let mut assigned: u64 = 0;
+		for (namespace, proof) in proofs {
+			if !ProofIds::<T>::contains_key(&namespace, &proof) {
+				Pallet::<T>::assign_claim_id(&namespace, &proof);
+				assigned += 1;
+			}
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+		log::info!(
+			target: "runtime::poe",
+			"assigned ids to {} proofs for storage version 3",
+			assigned,
+		);
+
+		T::DbWeight::get().reads_writes(1 + 2 * count, 1 + 3 * assigned)
+	}
+}
+
+/// 根据已有存证记录的押金计算 `TotalReservedDeposits`, 之后押金总额随存证的创建和移除更新.
+pub mod v4 {
+	use super::*;
+
+	/// 执行迁移. 链上的存储版本已经不低于 4 时不做任何修改, 因此可以安全地重复执行.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain = Pallet::<T>::on_chain_storage_version();
+		if on_chain >= 4 {
+			log::info!(
+				target: "runtime::poe",
+				"skipping v4 migration: on-chain storage version is {:?}",
+				on_chain,
+			);
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut count: u64 = 0;
+		let mut total: BalanceOf<T> = Zero::zero();
+		for claim in Proofs::<T>::iter_values() {
+			count += 1;
+			total = total.saturating_add(claim.deposit);
+		}
+
+		TotalReservedDeposits::<T>::put(total);
+		StorageVersion::new(4).put::<Pallet<T>>();
+		log::info!(
+			target: "runtime::poe",
+			"recorded the deposits of {} proofs for storage version 4",
+			count,
+		);
+
+		T::DbWeight::get().reads_writes(1 + count, 2)
+	}
+}
+
+/// 将已有的过期记录加入 `ExpiredRecordQueue`, 之后过期记录的数量不超过 `MaxExpiredRecords`.
+/// 超出上限的记录没有先后顺序可言, 按存储顺序保留前 `MaxExpiredRecords` 条, 其余的被移除.
+pub mod v5 {
+	use super::*;
+
+	/// 执行迁移. 链上的存储版本已经不低于 5 时不做任何修改, 因此可以安全地重复执行.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain = Pallet::<T>::on_chain_storage_version();
+		if on_chain >= 5 {
+			log::info!(
+				target: "runtime::poe",
+				"skipping v5 migration: on-chain storage version is {:?}",
+				on_chain,
+			);
+			return T::DbWeight::get().reads(1)
+		}
+
+		// 先取出全部记录再修改, 避免在迭代时修改存储
+		let records: Vec<(NamespaceOf<T>, BoundedVec<u8, T::MaxBytesInHash>)> =
+			ExpiredProofs::<T>::iter_keys().collect();
+		let count = records.len() as u64;
+		let max = T::MaxExpiredRecords::get() as u64;
+		let mut kept: u64 = 0;
+		for (namespace, proof) in records {
+			if kept < max {
+				ExpiredRecordQueue::<T>::insert(kept, (namespace.clone(), proof.clone()));
+				ExpiredRecordIndex::<T>::insert(&namespace, &proof, kept);
+				kept += 1;
+			} else {
+				ExpiredProofs::<T>::remove(&namespace, &proof);
+			}
+		}
+
+		ExpiredRecordRange::<T>::put((0, kept));
+		StorageVersion::new(5).put::<Pallet<T>>();
+		log::info!(
+			target: "runtime::poe",
+			"kept {} of {} expired records for storage version 5",
+			kept,
+			count,
+		);
+
+		T::DbWeight::get().reads_writes(1 + count, 2 + count + kept)
+	}
+}
+
+/// 在 `Blake2_128Concat` 和 `Twox64Concat` 之间切换 `Proofs` 中存证键的哈希算法.
+///
+/// 已有存证的链开启或关闭 `twox-proof-keys` 特性时, 需要在同一次运行时升级的
+/// `on_runtime_upgrade` 中执行 `proof_hasher::migrate::<Runtime, Old, ProofKeyHasher>()`,
+/// 其中 `Old` 是升级前使用的哈希算法. 该迁移不修改存储版本, 新旧哈希算法相同时不做任何修改.
+pub mod proof_hasher {
+	use super::*;
+
+	/// 执行迁移, 返回消耗的权重.
+	pub fn migrate<T, Old, New>() -> Weight
+	where
+		T: Config,
+		Old: ReversibleStorageHasher + StorageHasher<Output = Vec<u8>>,
+		New: StorageHasher<Output = Vec<u8>>,
+	{
+		if Old::METADATA == New::METADATA {
+			log::info!(target: "runtime::poe", "skipping proof hasher migration: hasher unchanged");
+			return 0
+		}
+
+		// 先取出全部条目再写入, 避免在迭代时遍历到新写入的键
+		let entries: Vec<(Vec<u8>, ClaimInfoOf<T>)> =
+			storage_iter::<ClaimInfoOf<T>>(pallet_name::<T>(), b"Proofs").drain().collect();
+		let count = entries.len() as u64;
+		let mut rekeyed: u64 = 0;
+		for (key, claim) in entries {
+			// 键的后缀为 `Blake2_128Concat(namespace) ++ Old(proof)`
+			let mut rest = Blake2_128Concat::reverse(&key);
+			if NamespaceOf::<T>::decode(&mut rest).is_err() {
+				log::warn!(target: "runtime::poe", "dropping Proofs entry with undecodable key");
+				continue
+			}
+			let namespace_key = &key[..key.len() - rest.len()];
+			let new_key = [namespace_key, &New::hash(Old::reverse(rest))[..]].concat();
+			put_storage_value(pallet_name::<T>(), b"Proofs", &new_key, claim);
+			rekeyed += 1;
+		}
+
+		log::info!(
+			target: "runtime::poe",
+			"rekeyed {} of {} proofs with the new proof hasher",
+			rekeyed,
+			count,
+		);
+
+		T::DbWeight::get().reads_writes(count, count + rekeyed)
+	}
+}
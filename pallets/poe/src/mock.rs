@@ -0,0 +1,368 @@
+use crate as pallet_poe;
+use crate::{
+	traits::{ExpiryScheduler, IdentityProvider, NoTimestamp, ProofNormalizer, QueryResponder},
+	NamespaceOf,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	parameter_types,
+	traits::{ConstBool, ConstU16, ConstU32, ConstU64, GenesisBuild, Get, Hooks},
+	weights::constants::RocksDbWeight,
+	BoundedVec,
+};
+use frame_system as system;
+use scale_info::TypeInfo;
+use sp_core::{sr25519, H256};
+use sp_keystore::{testing::KeyStore, KeystoreExt};
+use sp_runtime::{
+	testing::{Header, TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Lazy, Verify},
+	AccountId32, MultiSignature, MultiSigner,
+};
+use std::{cell::RefCell, collections::BTreeMap, sync::Arc};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		PoeModule: pallet_poe::{Pallet, Call, Storage, Config<T>, Event<T>},
+	}
+);
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = RocksDbWeight;
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static ClaimDeposit: u64 = 10;
+	pub static ClaimTtl: u64 = 0;
+	pub static AutoExpire: bool = true;
+	pub static MaxCreationsPerBlock: u32 = 0;
+	pub static TransferBond: u64 = 0;
+	pub static RequireIdentity: bool = false;
+	pub static UseScheduler: bool = false;
+}
+
+thread_local! {
+	/// `MockIdentity` 认为已登记身份的账户
+	static IDENTITIES: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+	/// `MockScheduler` 调度的任务, 每一项为命名空间、存证和执行的区块号
+	static SCHEDULED: RefCell<Vec<(Vec<u8>, Vec<u8>, u64)>> = RefCell::new(Vec::new());
+	/// `MockXcm` 发出的响应, 每一项为目标位置、查询编号和响应
+	static RESPONSES: RefCell<Vec<(u64, u64, Vec<u8>)>> = RefCell::new(Vec::new());
+	/// 基准测试中生成的 sr25519 公钥, 以其派生的账户为键
+	static SR25519_SIGNERS: RefCell<BTreeMap<u64, sr25519::Public>> = RefCell::new(BTreeMap::new());
+}
+
+/// 只有通过 `MockIdentity::register` 登记的账户拥有身份.
+pub struct MockIdentity;
+
+impl MockIdentity {
+	pub fn register(who: u64) {
+		IDENTITIES.with(|identities| identities.borrow_mut().push(who));
+	}
+}
+
+impl IdentityProvider<u64> for MockIdentity {
+	fn has_identity(who: &u64) -> bool {
+		IDENTITIES.with(|identities| identities.borrow().contains(who))
+	}
+}
+
+/// 去掉存证开头的所有 `0x` 前缀.
+pub struct StripHexPrefix;
+
+impl<S: Get<u32>> ProofNormalizer<S> for StripHexPrefix {
+	fn normalize(proof: BoundedVec<u8, S>) -> BoundedVec<u8, S> {
+		let mut bytes = &proof[..];
+		while bytes.starts_with(b"0x") {
+			bytes = &bytes[2..];
+		}
+		bytes.to_vec().try_into().expect("shorter than the original proof; qed")
+	}
+}
+
+/// 存证作者的公钥. 单元测试使用 `UintAuthorityId`, 基准测试使用 sr25519 公钥,
+/// 公钥的前 8 个字节作为派生的账户.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum MockSigner {
+	Test(UintAuthorityId),
+	Sr25519(sr25519::Public),
+}
+
+impl IdentifyAccount for MockSigner {
+	type AccountId = u64;
+
+	fn into_account(self) -> u64 {
+		match self {
+			MockSigner::Test(id) => id.0,
+			MockSigner::Sr25519(public) => {
+				let who = u64::from_le_bytes(public.0[..8].try_into().unwrap());
+				SR25519_SIGNERS.with(|signers| signers.borrow_mut().insert(who, public));
+				who
+			},
+		}
+	}
+}
+
+impl From<MultiSigner> for MockSigner {
+	fn from(signer: MultiSigner) -> Self {
+		match signer {
+			MultiSigner::Sr25519(public) => MockSigner::Sr25519(public),
+			_ => panic!("only sr25519 keys are used in benchmarks"),
+		}
+	}
+}
+
+/// 存证作者的签名, 与 `MockSigner` 对应.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub enum MockSignature {
+	Test(TestSignature),
+	Multi(MultiSignature),
+}
+
+impl Verify for MockSignature {
+	type Signer = MockSigner;
+
+	fn verify<L: Lazy<[u8]>>(&self, msg: L, signer: &u64) -> bool {
+		match self {
+			MockSignature::Test(signature) => signature.verify(msg, signer),
+			MockSignature::Multi(signature) => SR25519_SIGNERS.with(|signers| {
+				signers
+					.borrow()
+					.get(signer)
+					.map_or(false, |public| signature.verify(msg, &AccountId32::from(public.0)))
+			}),
+		}
+	}
+}
+
+impl From<MultiSignature> for MockSignature {
+	fn from(signature: MultiSignature) -> Self {
+		MockSignature::Multi(signature)
+	}
+}
+
+/// 在 `UseScheduler` 为 `true` 时记录调度的任务, 由 `MockScheduler::run` 执行.
+pub struct MockScheduler;
+
+impl MockScheduler {
+	/// 返回所有尚未执行的任务.
+	pub fn scheduled() -> Vec<(Vec<u8>, Vec<u8>, u64)> {
+		SCHEDULED.with(|tasks| tasks.borrow().clone())
+	}
+
+	/// 以 `Root` 来源执行在区块 `now` 调度的任务.
+	pub fn run(now: u64) {
+		let due = SCHEDULED.with(|tasks| {
+			let mut tasks = tasks.borrow_mut();
+			let due: Vec<_> = tasks.iter().filter(|(.., when)| *when == now).cloned().collect();
+			tasks.retain(|(.., when)| *when != now);
+			due
+		});
+		for (namespace, proof, _) in due {
+			PoeModule::handle_expiry(
+				Origin::root(),
+				namespace.try_into().unwrap(),
+				proof.try_into().unwrap(),
+			)
+			.unwrap();
+		}
+	}
+}
+
+impl ExpiryScheduler<u64> for MockScheduler {
+	fn schedule(namespace: &[u8], proof: &[u8], when: u64) -> bool {
+		if !UseScheduler::get() {
+			return false
+		}
+		SCHEDULED.with(|tasks| {
+			let mut tasks = tasks.borrow_mut();
+			if tasks.iter().any(|(ns, p, _)| ns == namespace && p == proof) {
+				return false
+			}
+			tasks.push((namespace.to_vec(), proof.to_vec(), when));
+			true
+		})
+	}
+
+	fn cancel(namespace: &[u8], proof: &[u8]) {
+		SCHEDULED
+			.with(|tasks| tasks.borrow_mut().retain(|(ns, p, _)| ns != namespace || p != proof));
+	}
+}
+
+/// 模拟跨链消息的路由, 记录发回发起查询的链的响应.
+pub struct MockXcm;
+
+impl MockXcm {
+	/// 取出所有已经发出的响应.
+	pub fn take_responses() -> Vec<(u64, u64, Vec<u8>)> {
+		RESPONSES.with(|responses| responses.borrow_mut().drain(..).collect())
+	}
+}
+
+impl QueryResponder<u64> for MockXcm {
+	fn respond(to: u64, query_id: u64, response: Vec<u8>) -> DispatchResult {
+		RESPONSES.with(|responses| responses.borrow_mut().push((to, query_id, response)));
+		Ok(())
+	}
+}
+
+impl pallet_poe::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type ClaimDeposit = ClaimDeposit;
+	type MinBytesInHash = ConstU32<4>;
+	type MaxBytesInHash = ConstU32<32>;
+	type MaxNamespaceLen = ConstU32<16>;
+	type MaxMetadataLength = ConstU32<64>;
+	type MaxMemoLen = ConstU32<32>;
+	type MaxMimeLen = ConstU32<32>;
+	type MaxKeyLen = ConstU32<16>;
+	type MaxValueLen = ConstU32<64>;
+	type MaxAttributesPerProof = ConstU32<4>;
+	type MaxTagLen = ConstU32<16>;
+	type MaxTagsPerProof = ConstU32<4>;
+	type MaxBatchSize = ConstU32<16>;
+	type ClaimTtl = ClaimTtl;
+	type MaxExpiredPerBlock = ConstU32<16>;
+	type MaxExpiredRecords = ConstU32<16>;
+	type AutoExpire = AutoExpire;
+	type IdleExpire = ConstBool<false>;
+	type ExpiryScheduler = MockScheduler;
+	type ExpiryWarningBlocks = ConstU64<0>;
+	type MaxExpiryWarningsPerBlock = ConstU32<16>;
+	type MaxProofs = ConstU32<1_000>;
+	type ClaimEviction = ConstBool<false>;
+	type PriorityDeposit = ConstU64<5>;
+	type MaxClaimsPerAccount = ConstU32<64>;
+	type MaxApprovers = ConstU32<4>;
+	type PendingClaimTtl = ConstU64<100>;
+	type MaxCreationsPerBlock = MaxCreationsPerBlock;
+	type MaxHistoryLength = ConstU32<16>;
+	type TransferCooldown = ConstU64<0>;
+	type MaxOwners = ConstU32<4>;
+	type MaxMetadataEditors = ConstU32<4>;
+	type MaxViewers = ConstU32<4>;
+	type MaxAllowedRecipients = ConstU32<4>;
+	type ClaimNotifications = ConstBool<false>;
+	type RecentBufferLen = ConstU32<16>;
+	type BloomBytes = ConstU32<0>;
+	type ChallengeBond = ConstU64<100>;
+	type TransferBond = TransferBond;
+	type RenewalFee = ConstU64<0>;
+	type ChallengePeriod = ConstU64<10>;
+	type RoyaltyBps = ConstU32<0>;
+	type PermissionedCreation = ConstBool<false>;
+	type RequireIdentity = RequireIdentity;
+	type IdentityProvider = MockIdentity;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type CouncilOrigin = frame_system::EnsureRoot<u64>;
+	type DepositWaiverOrigin = frame_system::EnsureRoot<u64>;
+	type UnixTime = NoTimestamp;
+	type OnClaimHandler = ();
+	type OnSlash = ();
+	type OnRenewalFee = ();
+	type AuthoritySignature = MockSignature;
+	type AuthoritySigner = MockSigner;
+	type ProofNormalizer = StripHexPrefix;
+	// 以账户模拟发起查询的链, 签名的来源即为对方链在本链上的主权账户
+	type QueryOrigin = frame_system::EnsureSigned<u64>;
+	type QueryLocation = u64;
+	type QueryResponder = MockXcm;
+	type WeightInfo = ();
+}
+
+/// 每个测试账户的初始余额
+pub const INITIAL_BALANCE: u64 = 1_000_000;
+
+/// 构造默认命名空间下的存证.
+pub fn proof(bytes: &[u8]) -> BoundedVec<u8, ConstU32<32>> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+/// 默认的(空的)命名空间.
+pub fn ns() -> NamespaceOf<Test> {
+	Default::default()
+}
+
+/// 将区块推进到 `n`, 并执行每个区块的 `on_initialize` 和调度的任务.
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		PoeModule::on_initialize(next);
+		MockScheduler::run(next);
+	}
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_proofs(Vec::new())
+}
+
+/// 与 `new_test_ext` 相同, 但在创世区块中预置 `proofs`.
+pub fn new_test_ext_with_proofs(
+	proofs: Vec<(NamespaceOf<Test>, BoundedVec<u8, ConstU32<32>>, u64)>,
+) -> sp_io::TestExternalities {
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=9).map(|who| (who, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_poe::GenesisConfig::<Test> { proofs }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	// 基准测试在密钥库中生成存证作者的密钥
+	ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
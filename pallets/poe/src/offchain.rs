@@ -0,0 +1,193 @@
+//! 将新创建的存证推送到外部索引服务的链下工作机.
+//!
+//! 链上的 `NewClaims` 只保存当前区块中创建的存证, 链下工作机在每个区块导入后读取它们,
+//! 与之前推送失败的存证一起以 JSON 的形式通过 HTTP POST 发送到节点本地配置的地址.
+//! 推送失败的存证保存在节点的链下存储中, 在下一个区块重试. 上一个区块的链下工作机仍在推送时,
+//! 新的存证先追加到链下存储的收件箱中, 由下一个取得锁的链下工作机一起推送.
+//!
+//! 推送地址保存在节点的持久化链下存储 `poe::ocw::endpoint` 中, 未设置时不推送, 例如:
+//! `offchain_localStorageSet("PERSISTENT", "poe::ocw::endpoint", "http://localhost:8080/claims")`
+
+use super::*;
+use frame_support::{
+	sp_io,
+	sp_runtime::{
+		offchain::{
+			http,
+			storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+			storage_lock::{StorageLock, Time},
+			Duration, StorageKind,
+		},
+		traits::UniqueSaturatedInto,
+	},
+};
+use sp_std::{prelude::*, vec};
+
+/// 推送地址在持久化链下存储中的键
+pub const ENDPOINT_KEY: &[u8] = b"poe::ocw::endpoint";
+/// 尚未推送成功的存证在持久化链下存储中的键
+const PENDING_KEY: &[u8] = b"poe::ocw::pending";
+/// 尚未并入待推送列表的新存证在持久化链下存储中的键, 不持有锁时也可以追加
+const INBOX_KEY: &[u8] = b"poe::ocw::inbox";
+/// 修改收件箱时与其他链下工作机冲突的最大重试次数
+const MAX_INBOX_RETRIES: usize = 5;
+/// 防止多个链下工作机同时推送的锁
+const LOCK_KEY: &[u8] = b"poe::ocw::lock";
+/// 单次推送的超时时间(毫秒)
+const HTTP_TIMEOUT_MS: u64 = 5_000;
+/// 最多保留的待推送存证数量, 超出时丢弃最早的存证
+const MAX_PENDING: usize = 1_000;
+
+/// 等待推送的存证: 创建时的区块号、命名空间和存证
+type Pending = (u64, Vec<u8>, Vec<u8>);
+
+/// 推送 `block_number` 中创建的存证以及之前推送失败的存证. 任何错误都只记录日志.
+pub fn notify_new_claims<T: Config>(block_number: T::BlockNumber) {
+	let endpoint = match sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, ENDPOINT_KEY) {
+		Some(endpoint) if !endpoint.is_empty() => endpoint,
+		_ => return,
+	};
+	let endpoint = match sp_std::str::from_utf8(&endpoint) {
+		Ok(endpoint) => endpoint,
+		Err(_) => {
+			log::warn!(target: "runtime::poe", "claim notification endpoint is not valid UTF-8");
+			return
+		},
+	};
+
+	// 链上的 `NewClaims` 只保留一个区块, 因此在尝试取得锁之前先将其追加到收件箱
+	let block: u64 = block_number.unique_saturated_into();
+	let new_claims: Vec<Pending> = NewClaims::<T>::get()
+		.into_iter()
+		.map(|(namespace, proof)| (block, namespace.into_inner(), proof.into_inner()))
+		.collect();
+	if !new_claims.is_empty() &&
+		mutate_inbox(|inbox| inbox.extend(new_claims.iter().cloned())).is_none()
+	{
+		log::warn!(
+			target: "runtime::poe",
+			"failed to queue {} claim notifications of block {}",
+			new_claims.len(),
+			block,
+		);
+	}
+
+	// 上一个区块的链下工作机仍在推送时跳过, 收件箱中的存证由下一个取得锁的链下工作机推送
+	let mut lock = StorageLock::<Time>::new(LOCK_KEY);
+	let _guard = match lock.try_lock() {
+		Ok(guard) => guard,
+		Err(_) => {
+			log::warn!(target: "runtime::poe", "claim notifications are still being pushed");
+			return
+		},
+	};
+
+	// 待推送列表只在持有锁时修改, 收件箱可能同时被其他链下工作机追加
+	let mut store = StorageValueRef::persistent(PENDING_KEY);
+	let mut pending: Vec<Pending> = store.get().ok().flatten().unwrap_or_default();
+	pending.extend(mutate_inbox(sp_std::mem::take).unwrap_or_default());
+	if pending.is_empty() {
+		return
+	}
+	if pending.len() > MAX_PENDING {
+		let dropped = pending.len() - MAX_PENDING;
+		log::warn!(target: "runtime::poe", "dropping {} unsent claim notifications", dropped);
+		pending.drain(..dropped);
+	}
+
+	match send(endpoint, &pending) {
+		Ok(()) => store.clear(),
+		Err(err) => {
+			log::warn!(
+				target: "runtime::poe",
+				"failed to push {} claim notifications, retrying next block: {:?}",
+				pending.len(),
+				err,
+			);
+			store.set(&pending);
+		},
+	}
+}
+
+/// 以比较并交换的方式修改收件箱, 与其他链下工作机的修改冲突时重试. 收件箱中的存证超过
+/// `MAX_PENDING` 时丢弃最早的存证. 无法修改时返回 `None`.
+fn mutate_inbox<R>(mut f: impl FnMut(&mut Vec<Pending>) -> R) -> Option<R> {
+	let inbox = StorageValueRef::persistent(INBOX_KEY);
+	for _ in 0..MAX_INBOX_RETRIES {
+		let mut result = None;
+		let mutated =
+			inbox.mutate(|stored: Result<Option<Vec<Pending>>, StorageRetrievalError>| {
+				let mut claims = stored.ok().flatten().unwrap_or_default();
+				result = Some(f(&mut claims));
+				if claims.len() > MAX_PENDING {
+					claims.drain(..claims.len() - MAX_PENDING);
+				}
+				Ok::<_, ()>(claims)
+			});
+		match mutated {
+			Ok(_) => return result,
+			Err(MutateStorageError::ConcurrentModification(_)) => continue,
+			Err(MutateStorageError::ValueFunctionFailed(())) => return None,
+		}
+	}
+	None
+}
+
+/// 将存证以 JSON 数组的形式发送到 `endpoint`, 只有 2xx 响应才被视为成功.
+fn send(endpoint: &str, pending: &[Pending]) -> Result<(), http::Error> {
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+	let request = http::Request::post(endpoint, vec![to_json(pending)])
+		.add_header("Content-Type", "application/json")
+		.deadline(deadline)
+		.send()
+		.map_err(|_| http::Error::IoError)?;
+	let response = request.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+	if !(200..300).contains(&response.code) {
+		log::warn!(target: "runtime::poe", "claim notification endpoint returned {}", response.code);
+		return Err(http::Error::Unknown)
+	}
+	Ok(())
+}
+
+/// 编码为 `[{"block":1,"namespace":"0x..","proof":"0x.."},..]`.
+fn to_json(pending: &[Pending]) -> Vec<u8> {
+	let mut json = vec![b'['];
+	for (i, (block, namespace, proof)) in pending.iter().enumerate() {
+		if i > 0 {
+			json.push(b',');
+		}
+		json.extend_from_slice(b"{\"block\":");
+		push_decimal(&mut json, *block);
+		json.extend_from_slice(b",\"namespace\":");
+		push_hex(&mut json, namespace);
+		json.extend_from_slice(b",\"proof\":");
+		push_hex(&mut json, proof);
+		json.push(b'}');
+	}
+	json.push(b']');
+	json
+}
+
+fn push_decimal(out: &mut Vec<u8>, mut value: u64) {
+	let mut digits = [0u8; 20];
+	let mut start = digits.len();
+	loop {
+		start -= 1;
+		digits[start] = b'0' + (value % 10) as u8;
+		value /= 10;
+		if value == 0 {
+			break
+		}
+	}
+	out.extend_from_slice(&digits[start..]);
+}
+
+fn push_hex(out: &mut Vec<u8>, bytes: &[u8]) {
+	const HEX: &[u8; 16] = b"0123456789abcdef";
+	out.extend_from_slice(b"\"0x");
+	for byte in bytes {
+		out.push(HEX[(byte >> 4) as usize]);
+		out.push(HEX[(byte & 0x0f) as usize]);
+	}
+	out.push(b'"');
+}
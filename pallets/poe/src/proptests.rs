@@ -0,0 +1,127 @@
+//! 以随机的操作序列检查存证状态机的不变量.
+
+use crate::{mock::*, HashAlgo, ProofCount, Proofs};
+use frame_support::{traits::ConstU32, BoundedVec};
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+
+/// 参与操作的账户数量
+const ACCOUNTS: u64 = 3;
+/// 参与操作的存证数量
+const PROOFS: u8 = 4;
+
+#[derive(Clone, Debug)]
+enum Op {
+	Create { who: u64, proof: u8 },
+	Transfer { who: u64, to: u64, proof: u8 },
+	Revoke { who: u64, proof: u8 },
+}
+
+/// 模型中存证的状态, 不在模型中的存证从未被创建
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+	Owned(u64),
+	Revoked,
+}
+
+fn account() -> impl Strategy<Value = u64> {
+	1..=ACCOUNTS
+}
+
+fn proof_index() -> impl Strategy<Value = u8> {
+	0..PROOFS
+}
+
+fn op() -> impl Strategy<Value = Op> {
+	prop_oneof![
+		(account(), proof_index()).prop_map(|(who, proof)| Op::Create { who, proof }),
+		(account(), account(), proof_index()).prop_map(|(who, to, proof)| Op::Transfer {
+			who,
+			to,
+			proof
+		}),
+		(account(), proof_index()).prop_map(|(who, proof)| Op::Revoke { who, proof }),
+	]
+}
+
+fn proof_of(index: u8) -> BoundedVec<u8, ConstU32<32>> {
+	proof(format!("proof-{}", index).as_bytes())
+}
+
+/// 执行 `op` 并返回调用是否成功.
+fn apply(op: &Op) -> bool {
+	match *op {
+		Op::Create { who, proof } => PoeModule::create_claim(
+			Origin::signed(who),
+			ns(),
+			proof_of(proof),
+			HashAlgo::Blake2_256,
+		)
+		.is_ok(),
+		Op::Transfer { who, to, proof } =>
+			PoeModule::transfer_claim(Origin::signed(who), to, ns(), proof_of(proof)).is_ok(),
+		Op::Revoke { who, proof } =>
+			PoeModule::revoke_claim(Origin::signed(who), ns(), proof_of(proof)).is_ok(),
+	}
+}
+
+/// 按照模型执行 `op`, 返回调用是否应当成功.
+fn apply_to_model(model: &mut BTreeMap<u8, State>, op: &Op) -> bool {
+	match *op {
+		// 已被撤销的存证在被彻底删除之前无法再次声明
+		Op::Create { who, proof } => {
+			if model.contains_key(&proof) {
+				return false
+			}
+			model.insert(proof, State::Owned(who));
+			true
+		},
+		Op::Transfer { who, to, proof } => match model.get(&proof) {
+			Some(State::Owned(owner)) if *owner == who && who != to => {
+				model.insert(proof, State::Owned(to));
+				true
+			},
+			_ => false,
+		},
+		Op::Revoke { who, proof } => match model.get(&proof) {
+			Some(State::Owned(owner)) if *owner == who => {
+				model.insert(proof, State::Revoked);
+				true
+			},
+			_ => false,
+		},
+	}
+}
+
+/// 检查链上的状态与模型一致.
+fn check_against_model(model: &BTreeMap<u8, State>) -> Result<(), TestCaseError> {
+	for index in 0..PROOFS {
+		let expected = match model.get(&index) {
+			Some(State::Owned(owner)) => Some(*owner),
+			_ => None,
+		};
+		let actual = Proofs::<Test>::get(ns(), proof_of(index)).map(|claim| claim.owner);
+		prop_assert_eq!(actual, expected, "owner of proof {}", index);
+	}
+
+	let live = model.values().filter(|state| matches!(state, State::Owned(_))).count();
+	prop_assert_eq!(ProofCount::<Test>::get() as usize, live);
+	prop_assert_eq!(Proofs::<Test>::iter().count(), live);
+	prop_assert!(PoeModule::check_invariants().is_ok());
+	Ok(())
+}
+
+proptest! {
+	#[test]
+	fn state_machine_matches_model(ops in prop::collection::vec(op(), 1..40)) {
+		new_test_ext().execute_with(|| {
+			let mut model = BTreeMap::new();
+			for op in &ops {
+				let expected = apply_to_model(&mut model, op);
+				prop_assert_eq!(apply(op), expected, "{:?}", op);
+				check_against_model(&model)?;
+			}
+			Ok(())
+		})?;
+	}
+}
@@ -0,0 +1,988 @@
+use crate::{
+	extensions::{CheckProofSize, INVALID_PROOF_LENGTH},
+	mock::*,
+	AccountStats, Approvals, ClaimStats, ContentTypes, Error, Event as PoeEvent, ExpiredProofs,
+	ExpiryQueue, HashAlgo, MetadataVersion, ProofCount, ProofExpiry, Proofs, TombstoneDeposits,
+	TotalReservedDeposits, TransferBonds, WeightInfo,
+};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchInfo, traits::Get, BoundedVec};
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{Dispatchable, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+/// 与 `assert_noop!` 相同, 但忽略错误中的实际权重.
+macro_rules! assert_noop_ignore_postinfo {
+	($x:expr, $y:expr $(,)?) => {
+		assert_noop!($x.map_err(|e| e.error), $y)
+	};
+}
+
+/// 存证 `proof-<index>`.
+fn nth_proof(index: u32) -> BoundedVec<u8, frame_support::traits::ConstU32<32>> {
+	proof(format!("proof-{}", index).as_bytes())
+}
+
+/// 由 `who` 在默认命名空间下创建存证.
+fn create(who: u64, proof: &BoundedVec<u8, frame_support::traits::ConstU32<32>>) {
+	assert_ok!(PoeModule::create_claim(
+		Origin::signed(who),
+		ns(),
+		proof.clone(),
+		HashAlgo::Blake2_256
+	));
+}
+
+/// 返回 pallet 发出的所有事件.
+fn poe_events() -> Vec<PoeEvent<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			Event::PoeModule(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn proof_count_matches_storage() {
+	new_test_ext().execute_with(|| {
+		for i in 0..20 {
+			create(1 + i as u64 % 3, &nth_proof(i));
+		}
+		assert_ok!(PoeModule::create_claims(
+			Origin::signed(4),
+			ns(),
+			vec![nth_proof(100), nth_proof(101), nth_proof(0)].try_into().unwrap(),
+		));
+		for i in (0..20).step_by(4) {
+			assert_ok!(PoeModule::revoke_claim(
+				Origin::signed(1 + i as u64 % 3),
+				ns(),
+				nth_proof(i)
+			));
+		}
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), ns(), nth_proof(1)));
+		assert_noop_ignore_postinfo!(
+			PoeModule::revoke_claim(Origin::signed(1), ns(), nth_proof(0)),
+			Error::<Test>::NoSuchProof
+		);
+
+		assert_eq!(ProofCount::<Test>::get(), 16);
+		assert_eq!(PoeModule::total_proofs() as usize, Proofs::<Test>::iter().count());
+		assert_ok!(PoeModule::check_invariants());
+	});
+}
+
+#[test]
+fn create_claims_charges_lookup_for_skipped_proofs() {
+	new_test_ext().execute_with(|| {
+		create(1, &nth_proof(0));
+
+		let post_info = PoeModule::create_claims(
+			Origin::signed(1),
+			ns(),
+			vec![nth_proof(0), nth_proof(1), nth_proof(2)].try_into().unwrap(),
+		)
+		.unwrap();
+		// 跳过的存证只收取查询所需的权重
+		let lookup = <Test as frame_system::Config>::DbWeight::get().reads(2);
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::create_claims(2) + lookup));
+
+		let post_info = PoeModule::create_claims(
+			Origin::signed(1),
+			ns(),
+			vec![nth_proof(3), nth_proof(4)].try_into().unwrap(),
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::create_claims(2)));
+	});
+}
+
+#[test]
+fn revoking_missing_proof_only_charges_lookup() {
+	new_test_ext().execute_with(|| {
+		let err = PoeModule::revoke_claim(Origin::signed(1), ns(), nth_proof(0)).unwrap_err();
+		assert_eq!(err.error, Error::<Test>::NoSuchProof.into());
+		let lookup = <Test as frame_system::Config>::DbWeight::get().reads(2);
+		assert_eq!(err.post_info.actual_weight, Some(lookup));
+		assert!(lookup < <() as WeightInfo>::revoke_claim());
+
+		create(1, &nth_proof(0));
+		let post_info = PoeModule::revoke_claim(Origin::signed(1), ns(), nth_proof(0)).unwrap();
+		assert_eq!(post_info.actual_weight, None);
+	});
+}
+
+#[test]
+fn creations_are_rate_limited_per_block() {
+	new_test_ext().execute_with(|| {
+		MaxCreationsPerBlock::set(2);
+		create(1, &nth_proof(0));
+		create(1, &nth_proof(1));
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim(Origin::signed(1), ns(), nth_proof(2), HashAlgo::Blake2_256),
+			Error::<Test>::RateLimited
+		);
+		// 其他账户不受影响
+		create(2, &nth_proof(2));
+
+		run_to_block(2);
+		create(1, &nth_proof(3));
+		create(1, &nth_proof(4));
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim(Origin::signed(1), ns(), nth_proof(5), HashAlgo::Blake2_256),
+			Error::<Test>::RateLimited
+		);
+	});
+}
+
+#[test]
+fn metadata_version_increments_monotonically() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		assert_eq!(MetadataVersion::<Test>::get(ns(), &proof), 0);
+
+		for version in 1..=3 {
+			let metadata = format!("revision {}", version).into_bytes().try_into().unwrap();
+			assert_ok!(PoeModule::set_metadata(Origin::signed(1), ns(), proof.clone(), metadata));
+			assert_eq!(MetadataVersion::<Test>::get(ns(), &proof), version);
+			assert_eq!(
+				poe_events().last(),
+				Some(&PoeEvent::MetadataSet(1, ns(), proof.clone(), version))
+			);
+		}
+
+		// 撤销存证时计数被移除
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ns(), proof.clone()));
+		assert!(!MetadataVersion::<Test>::contains_key(ns(), &proof));
+	});
+}
+
+#[test]
+fn deposit_events_carry_the_reserved_amount() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		assert!(poe_events().contains(&PoeEvent::DepositReserved(1, ns(), proof.clone(), 10)));
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		// 押金保留到撤销记录被删除
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ns(), proof.clone()));
+		assert!(!poe_events().iter().any(|e| matches!(e, PoeEvent::DepositUnreserved(..))));
+		assert_ok!(PoeModule::burn_claim(Origin::signed(1), ns(), proof.clone()));
+		assert!(poe_events().contains(&PoeEvent::DepositUnreserved(1, ns(), proof, 10)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// 押金为 0 时不发出事件
+		System::reset_events();
+		ClaimDeposit::set(0);
+		create(2, &nth_proof(1));
+		assert_ok!(PoeModule::burn_claim(Origin::signed(2), ns(), nth_proof(1)));
+		assert!(!poe_events()
+			.iter()
+			.any(|e| matches!(e, PoeEvent::DepositReserved(..) | PoeEvent::DepositUnreserved(..))));
+	});
+}
+
+#[test]
+fn creation_requires_identity_when_enabled() {
+	new_test_ext().execute_with(|| {
+		RequireIdentity::set(true);
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim(Origin::signed(1), ns(), nth_proof(0), HashAlgo::Blake2_256),
+			Error::<Test>::IdentityRequired
+		);
+
+		MockIdentity::register(1);
+		create(1, &nth_proof(0));
+
+		// `safe_transfer_claim` 同样要求接收者已登记身份
+		assert_noop_ignore_postinfo!(
+			PoeModule::safe_transfer_claim(Origin::signed(1), 2, ns(), nth_proof(0)),
+			Error::<Test>::IdentityRequired
+		);
+		MockIdentity::register(2);
+		assert_ok!(PoeModule::safe_transfer_claim(Origin::signed(1), 2, ns(), nth_proof(0)));
+	});
+}
+
+/// 以 `CheckProofSize` 验证由账户 1 提交的 `call`.
+fn validate_proof_size(call: crate::Call<Test>) -> Result<(), TransactionValidityError> {
+	CheckProofSize::<Test>::new()
+		.validate(&1, &Call::PoeModule(call), &DispatchInfo::default(), 0)
+		.map(|_| ())
+}
+
+#[test]
+fn check_proof_size_validates_the_normalized_proof() {
+	new_test_ext().execute_with(|| {
+		let invalid = Err(InvalidTransaction::Custom(INVALID_PROOF_LENGTH).into());
+		let create_claim = |bytes: &[u8]| crate::Call::create_claim {
+			namespace: ns(),
+			proof: proof(bytes),
+			algo: HashAlgo::Blake2_256,
+		};
+
+		assert_eq!(validate_proof_size(create_claim(b"abcd")), Ok(()));
+		assert_eq!(validate_proof_size(create_claim(b"0xabcd")), Ok(()));
+		assert_eq!(validate_proof_size(create_claim(b"abc")), invalid);
+		// 规范形式只有 3 个字节
+		assert_eq!(validate_proof_size(create_claim(b"0xabc")), invalid);
+
+		assert_eq!(
+			validate_proof_size(crate::Call::create_claims {
+				namespace: ns(),
+				proofs: vec![proof(b"abcd"), proof(b"0x0xabc")].try_into().unwrap(),
+			}),
+			invalid
+		);
+		assert_eq!(
+			validate_proof_size(crate::Call::update_claim {
+				namespace: ns(),
+				old_proof: proof(b"abcd"),
+				new_proof: proof(b"0xab"),
+			}),
+			invalid
+		);
+		// 超长的存证由 `create_claim_unbounded` 在执行时报告
+		assert_eq!(
+			validate_proof_size(crate::Call::create_claim_unbounded {
+				namespace: ns(),
+				proof: vec![0; 33],
+				algo: HashAlgo::Blake2_256,
+			}),
+			Ok(())
+		);
+		// 其他调用不受影响
+		assert_eq!(
+			validate_proof_size(crate::Call::revoke_claim { namespace: ns(), proof: proof(b"ab") }),
+			Ok(())
+		);
+	});
+}
+
+#[test]
+fn transfer_bond_is_refunded_on_cancel() {
+	new_test_ext().execute_with(|| {
+		TransferBond::set(50);
+		let proof = nth_proof(0);
+		create(1, &proof);
+
+		assert_ok!(PoeModule::offer_transfer(Origin::signed(1), 2, ns(), proof.clone()));
+		assert!(poe_events().contains(&PoeEvent::TransferBondReserved(1, ns(), proof.clone(), 50)));
+		assert_eq!(Balances::reserved_balance(1), 10 + 50);
+
+		assert_ok!(PoeModule::cancel_transfer(Origin::signed(1), ns(), proof.clone()));
+		assert!(poe_events().contains(&PoeEvent::TransferBondReleased(1, ns(), proof.clone(), 50)));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert!(!TransferBonds::<Test>::contains_key(ns(), &proof));
+	});
+}
+
+#[test]
+fn transfer_bond_is_refunded_on_accept() {
+	new_test_ext().execute_with(|| {
+		TransferBond::set(50);
+		let proof = nth_proof(0);
+		create(1, &proof);
+
+		assert_ok!(PoeModule::offer_transfer(Origin::signed(1), 2, ns(), proof.clone()));
+		// 覆盖之前的转移时, 之前的保证金立即退还
+		assert_ok!(PoeModule::offer_transfer(Origin::signed(1), 3, ns(), proof.clone()));
+		assert_eq!(Balances::reserved_balance(1), 10 + 50);
+
+		assert_ok!(PoeModule::accept_transfer(Origin::signed(3), ns(), proof.clone()));
+		assert!(poe_events().contains(&PoeEvent::TransferBondReleased(1, ns(), proof.clone(), 50)));
+		assert_eq!(Proofs::<Test>::get(ns(), &proof).unwrap().owner, 3);
+		// 存证的押金仍然由创建者保留
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(!TransferBonds::<Test>::contains_key(ns(), &proof));
+	});
+}
+
+#[test]
+fn zero_transfer_bond_reserves_nothing() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+
+		assert_ok!(PoeModule::offer_transfer(Origin::signed(1), 2, ns(), proof.clone()));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert!(!TransferBonds::<Test>::contains_key(ns(), &proof));
+		assert!(!poe_events().iter().any(|e| matches!(e, PoeEvent::TransferBondReserved(..))));
+	});
+}
+
+#[test]
+fn recreating_revoked_proof_reports_the_revocation() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		run_to_block(3);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ns(), proof.clone()));
+
+		run_to_block(5);
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim(Origin::signed(2), ns(), proof.clone(), HashAlgo::Blake2_256),
+			Error::<Test>::AlreadyRevoked
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim_with_priority(
+				Origin::signed(2),
+				ns(),
+				proof.clone(),
+				HashAlgo::Blake2_256,
+				1
+			),
+			Error::<Test>::AlreadyRevoked
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_shared_claim(
+				Origin::signed(2),
+				ns(),
+				vec![3].try_into().unwrap(),
+				proof.clone()
+			),
+			Error::<Test>::AlreadyRevoked
+		);
+		// 客户端可以查询撤销者和撤销时的区块号
+		assert_eq!(PoeModule::revocation_info(ns().to_vec(), proof.to_vec()), Some((1, 3)));
+
+		// 撤销记录被删除之后可以再次声明
+		assert_ok!(PoeModule::burn_claim(Origin::signed(1), ns(), proof.clone()));
+		create(2, &proof);
+	});
+}
+
+/// 账户的存证统计 `(active, created, revoked)`.
+fn stats(who: u64) -> (u32, u32, u32) {
+	let ClaimStats { active, created, revoked } = PoeModule::stats_of(&who);
+	(active, created, revoked)
+}
+
+#[test]
+fn account_stats_follow_transfers() {
+	new_test_ext().execute_with(|| {
+		create(1, &nth_proof(0));
+		create(1, &nth_proof(1));
+		assert_eq!(stats(1), (2, 2, 0));
+
+		// 转移只改变双方的 active, 不改变 created
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), 2, ns(), nth_proof(0)));
+		assert_eq!(stats(1), (1, 2, 0));
+		assert_eq!(stats(2), (1, 0, 0));
+
+		// 转移给自己失败时统计不变
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_claim(Origin::signed(2), 2, ns(), nth_proof(0)),
+			Error::<Test>::CannotTransferToSelf
+		);
+
+		// 转移回创建者
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(2), 1, ns(), nth_proof(0)));
+		assert_eq!(stats(1), (2, 2, 0));
+		assert_eq!(stats(2), (0, 0, 0));
+
+		// 撤销计入撤销时的持有者
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), 2, ns(), nth_proof(0)));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ns(), nth_proof(0)));
+		assert_eq!(stats(1), (1, 2, 0));
+		assert_eq!(stats(2), (0, 0, 1));
+	});
+}
+
+#[test]
+fn account_stats_never_underflow() {
+	new_test_ext().execute_with(|| {
+		create(1, &nth_proof(0));
+		// 模拟统计与存证不一致(例如统计上线之前创建的存证)
+		AccountStats::<Test>::remove(1);
+
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), 2, ns(), nth_proof(0)));
+		assert_eq!(stats(1), (0, 0, 0));
+		assert_eq!(stats(2), (1, 0, 0));
+
+		AccountStats::<Test>::remove(2);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), ns(), nth_proof(0)));
+		assert_eq!(stats(2), (0, 0, 1));
+	});
+}
+
+#[test]
+fn pinned_claim_survives_expiry() {
+	new_test_ext().execute_with(|| {
+		ClaimTtl::set(5);
+		let (pinned, unpinned) = (nth_proof(0), nth_proof(1));
+		create(1, &pinned);
+		create(1, &unpinned);
+		assert_ok!(PoeModule::pin_claim(Origin::signed(1), ns(), pinned.clone()));
+		assert_eq!(poe_events().last(), Some(&PoeEvent::ClaimPinned(ns(), pinned.clone())));
+
+		run_to_block(6);
+		assert!(Proofs::<Test>::contains_key(ns(), &pinned));
+		assert!(!Proofs::<Test>::contains_key(ns(), &unpinned));
+		assert!(poe_events().contains(&PoeEvent::ClaimExpired(1, ns(), unpinned)));
+
+		// 固定期间过期区块不起作用
+		run_to_block(10);
+		assert!(Proofs::<Test>::contains_key(ns(), &pinned));
+
+		// 取消固定后在下一个区块过期
+		assert_ok!(PoeModule::unpin_claim(Origin::signed(1), ns(), pinned.clone()));
+		assert_eq!(poe_events().last(), Some(&PoeEvent::ClaimUnpinned(ns(), pinned.clone())));
+		run_to_block(11);
+		assert!(!Proofs::<Test>::contains_key(ns(), &pinned));
+		assert!(poe_events().contains(&PoeEvent::ClaimExpired(1, ns(), pinned)));
+	});
+}
+
+/// 模拟对方链的主权账户
+const SIBLING: u64 = 100;
+
+/// 像 `Transact` 一样解码并以对方链的来源执行编码后的 `query_claim` 调用,
+/// 返回发回对方链的响应.
+fn transact_query(
+	query_id: u64,
+	proof: &BoundedVec<u8, frame_support::traits::ConstU32<32>>,
+) -> Option<(u64, u64)> {
+	let encoded = Call::PoeModule(crate::Call::query_claim {
+		query_id,
+		namespace: ns(),
+		proof: proof.clone(),
+	})
+	.encode();
+	let call = Call::decode(&mut &encoded[..]).unwrap();
+	assert_ok!(call.dispatch(Origin::signed(SIBLING)));
+
+	let responses = MockXcm::take_responses();
+	assert_eq!(responses.len(), 1);
+	let (to, id, response) = responses[0].clone();
+	assert_eq!((to, id), (SIBLING, query_id));
+	assert_eq!(
+		poe_events().last(),
+		Some(&PoeEvent::ClaimQueryAnswered(query_id, response.clone()))
+	);
+	Decode::decode(&mut &response[..]).unwrap()
+}
+
+#[test]
+fn query_claim_responds_to_the_querying_chain() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		assert_eq!(transact_query(1, &proof), None);
+
+		create(1, &proof);
+		assert_eq!(transact_query(2, &proof), Some((1, 1)));
+
+		// 私有的存证对其他链同样不可见
+		assert_ok!(PoeModule::set_private(Origin::signed(1), ns(), proof.clone(), true));
+		assert_eq!(transact_query(3, &proof), None);
+
+		// 只接受 `QueryOrigin` 的查询
+		assert_noop!(
+			PoeModule::query_claim(Origin::root(), 4, ns(), proof),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert!(MockXcm::take_responses().is_empty());
+	});
+}
+
+#[test]
+fn scheduled_expiry_fires_at_the_expiry_block() {
+	new_test_ext().execute_with(|| {
+		UseScheduler::set(true);
+		ClaimTtl::set(5);
+		let proof = nth_proof(0);
+		create(1, &proof);
+		assert_eq!(MockScheduler::scheduled(), vec![(vec![], proof.to_vec(), 6)]);
+		// 调度成功时不使用过期队列
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
+
+		// 续期时取消原来的任务并重新调度
+		run_to_block(3);
+		assert_ok!(PoeModule::renew_claim(Origin::signed(1), ns(), proof.clone()));
+		assert_eq!(ProofExpiry::<Test>::get(ns(), &proof), Some(8));
+		assert_eq!(MockScheduler::scheduled(), vec![(vec![], proof.to_vec(), 8)]);
+
+		// 撤销时取消调度的任务
+		let revoked = nth_proof(1);
+		create(1, &revoked);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), ns(), revoked));
+		assert_eq!(MockScheduler::scheduled(), vec![(vec![], proof.to_vec(), 8)]);
+
+		run_to_block(7);
+		assert!(Proofs::<Test>::contains_key(ns(), &proof));
+		run_to_block(8);
+		assert!(!Proofs::<Test>::contains_key(ns(), &proof));
+		assert!(poe_events().contains(&PoeEvent::ClaimExpired(1, ns(), proof)));
+		assert!(MockScheduler::scheduled().is_empty());
+	});
+}
+
+#[test]
+fn handle_expiry_requires_force_origin_and_an_expired_claim() {
+	new_test_ext().execute_with(|| {
+		UseScheduler::set(true);
+		ClaimTtl::set(5);
+		let proof = nth_proof(0);
+		create(1, &proof);
+
+		assert_noop!(
+			PoeModule::handle_expiry(Origin::signed(1), ns(), proof.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		// 尚未过期的存证不受影响
+		assert_ok!(PoeModule::handle_expiry(Origin::root(), ns(), proof.clone()));
+		assert!(Proofs::<Test>::contains_key(ns(), &proof));
+	});
+}
+
+/// 存证当前的持有者.
+fn owner_of(proof: &BoundedVec<u8, frame_support::traits::ConstU32<32>>) -> u64 {
+	Proofs::<Test>::get(ns(), proof).unwrap().owner
+}
+
+#[test]
+fn transfer_from_by_owner() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		assert_ok!(PoeModule::transfer_from(Origin::signed(1), ns(), proof.clone(), 2));
+		assert_eq!(owner_of(&proof), 2);
+	});
+}
+
+#[test]
+fn transfer_from_by_approved_spender() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		assert_ok!(PoeModule::approve_transfer(Origin::signed(1), ns(), proof.clone(), 3));
+		assert_ok!(PoeModule::transfer_from(Origin::signed(3), ns(), proof.clone(), 4));
+		assert_eq!(owner_of(&proof), 4);
+
+		// 转移后授权被清除
+		assert!(!Approvals::<Test>::contains_key(ns(), &proof));
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_from(Origin::signed(3), ns(), proof.clone(), 3),
+			Error::<Test>::NotProofOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_from_by_unauthorized_caller() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+
+		// 存证没有任何授权
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_from(Origin::signed(2), ns(), proof.clone(), 2),
+			Error::<Test>::NotProofOwner
+		);
+
+		// 存证已授权给其他账户
+		assert_ok!(PoeModule::approve_transfer(Origin::signed(1), ns(), proof.clone(), 3));
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_from(Origin::signed(2), ns(), proof.clone(), 2),
+			Error::<Test>::NotApproved
+		);
+		assert_eq!(owner_of(&proof), 1);
+	});
+}
+
+/// 遍历存储得到的所有存证和撤销记录的押金之和.
+fn stored_deposits() -> u64 {
+	Proofs::<Test>::iter_values().map(|claim| claim.deposit).sum::<u64>() +
+		TombstoneDeposits::<Test>::iter_values()
+			.map(|(_, deposit)| deposit)
+			.sum::<u64>()
+}
+
+#[test]
+fn total_deposits_match_storage_over_many_cycles() {
+	new_test_ext().execute_with(|| {
+		for round in 0..10u32 {
+			ClaimDeposit::set(5 + round as u64);
+			for i in 0..6 {
+				create(1 + (round + i) as u64 % 3, &nth_proof(round * 10 + i));
+			}
+			for i in 0..6 {
+				let (who, proof) = (1 + (round + i) as u64 % 3, nth_proof(round * 10 + i));
+				match i % 3 {
+					0 => assert_ok!(PoeModule::revoke_claim(Origin::signed(who), ns(), proof)),
+					1 => assert_ok!(PoeModule::burn_claim(Origin::signed(who), ns(), proof)),
+					_ => (),
+				}
+			}
+			// 删除上一轮留下的撤销记录
+			if round > 0 {
+				let (who, proof) = (1 + (round - 1) as u64 % 3, nth_proof((round - 1) * 10));
+				assert_ok!(PoeModule::burn_claim(Origin::signed(who), ns(), proof));
+			}
+
+			assert_eq!(PoeModule::total_deposits(), stored_deposits());
+			let reserved: u64 = (1..=3).map(Balances::reserved_balance).sum();
+			assert_eq!(PoeModule::total_deposits(), reserved);
+		}
+		assert_ok!(PoeModule::check_invariants());
+	});
+}
+
+/// 作者 `author` 对使用 `nonce` 创建存证的签名.
+fn sign_claim(
+	author: u64,
+	proof: &BoundedVec<u8, frame_support::traits::ConstU32<32>>,
+	nonce: u64,
+) -> MockSignature {
+	MockSignature::Test(TestSignature(author, PoeModule::signed_claim_payload(&ns(), proof, nonce)))
+}
+
+#[test]
+fn replayed_signature_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		let signature = sign_claim(1, &proof, 0);
+		assert_ok!(PoeModule::create_claim_signed(
+			Origin::signed(2),
+			1,
+			ns(),
+			proof.clone(),
+			0,
+			signature.clone()
+		));
+		assert_eq!(owner_of(&proof), 1);
+		assert_eq!(PoeModule::account_nonce(&1), 1);
+
+		// 删除存证后重放同一个签名
+		assert_ok!(PoeModule::burn_claim(Origin::signed(1), ns(), proof.clone()));
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim_signed(Origin::signed(3), 1, ns(), proof.clone(), 0, signature),
+			Error::<Test>::BadNonce
+		);
+
+		// 尚未轮到的随机数同样被拒绝
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim_signed(
+				Origin::signed(2),
+				1,
+				ns(),
+				proof.clone(),
+				2,
+				sign_claim(1, &proof, 2)
+			),
+			Error::<Test>::BadNonce
+		);
+
+		// 使用当前的随机数重新签名
+		assert_ok!(PoeModule::create_claim_signed(
+			Origin::signed(2),
+			1,
+			ns(),
+			proof.clone(),
+			1,
+			sign_claim(1, &proof, 1)
+		));
+		assert_eq!(PoeModule::account_nonce(&1), 2);
+	});
+}
+
+#[test]
+fn signature_is_bound_to_the_chain() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		let payload = PoeModule::signed_claim_payload(&ns(), &proof, 0);
+		assert!(payload.starts_with(&b"poe/create".encode()));
+
+		// 在创世区块不同的链上签名
+		let signature = sign_claim(1, &proof, 0);
+		frame_system::BlockHash::<Test>::insert(0, sp_core::H256::repeat_byte(1));
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim_signed(Origin::signed(2), 1, ns(), proof.clone(), 0, signature),
+			Error::<Test>::BadSignature
+		);
+		assert_ok!(PoeModule::create_claim_signed(
+			Origin::signed(2),
+			1,
+			ns(),
+			proof.clone(),
+			0,
+			sign_claim(1, &proof, 0)
+		));
+	});
+}
+
+#[test]
+fn paused_pallet_takes_precedence_over_authorization() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		assert_ok!(PoeModule::set_paused(Origin::root(), true));
+
+		// 账户 2 无权操作账户 1 的存证, 但 pallet 被暂停时总是返回 `PalletPaused`
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_claim(Origin::signed(2), 3, ns(), proof.clone()),
+			Error::<Test>::PalletPaused
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::revoke_claim(Origin::signed(2), ns(), proof.clone()),
+			Error::<Test>::PalletPaused
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_from(Origin::signed(2), ns(), proof.clone(), 3),
+			Error::<Test>::PalletPaused
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::update_claim(Origin::signed(2), ns(), proof.clone(), nth_proof(1)),
+			Error::<Test>::PalletPaused
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_claims(
+				Origin::signed(2),
+				3,
+				ns(),
+				vec![proof.clone()].try_into().unwrap()
+			),
+			Error::<Test>::PalletPaused
+		);
+		// 没有身份的账户同样先看到 `PalletPaused`
+		RequireIdentity::set(true);
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claim(Origin::signed(2), ns(), nth_proof(1), HashAlgo::Blake2_256),
+			Error::<Test>::PalletPaused
+		);
+	});
+}
+
+#[test]
+fn frozen_account_takes_precedence_over_authorization() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		assert_ok!(PoeModule::freeze_account(Origin::root(), 2));
+
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_claim(Origin::signed(2), 3, ns(), proof.clone()),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::revoke_claim(Origin::signed(2), ns(), proof.clone()),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_from(Origin::signed(2), ns(), proof.clone(), 3),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::update_claim(Origin::signed(2), ns(), proof.clone(), nth_proof(1)),
+			Error::<Test>::AccountFrozen
+		);
+		// 暂停同时冻结时, 暂停优先
+		assert_ok!(PoeModule::set_paused(Origin::root(), true));
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_claim(Origin::signed(2), 3, ns(), proof),
+			Error::<Test>::PalletPaused
+		);
+	});
+}
+
+#[test]
+fn unauthorized_caller_sees_the_authorization_error() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+
+		assert_noop_ignore_postinfo!(
+			PoeModule::transfer_claim(Origin::signed(2), 3, ns(), proof.clone()),
+			Error::<Test>::NotProofOwner
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::revoke_claim(Origin::signed(2), ns(), proof.clone()),
+			Error::<Test>::NotProofOwner
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::update_claim(Origin::signed(2), ns(), proof.clone(), nth_proof(1)),
+			Error::<Test>::NotProofOwner
+		);
+		assert_noop_ignore_postinfo!(
+			PoeModule::revoke_claim(Origin::signed(2), ns(), nth_proof(1)),
+			Error::<Test>::NoSuchProof
+		);
+	});
+}
+
+#[test]
+fn create_claim_unbounded_accepts_a_proof_at_the_limit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim_unbounded(
+			Origin::signed(1),
+			ns(),
+			vec![7; 32],
+			HashAlgo::Blake2_256
+		));
+		assert_eq!(Proofs::<Test>::get(ns(), proof(&[7; 32])).unwrap().owner, 1);
+	});
+}
+
+#[test]
+fn create_claim_unbounded_rejects_a_proof_over_the_limit() {
+	new_test_ext().execute_with(|| {
+		let err = PoeModule::create_claim_unbounded(
+			Origin::signed(1),
+			ns(),
+			vec![7; 33],
+			HashAlgo::Blake2_256,
+		)
+		.unwrap_err();
+		assert_eq!(err.error, Error::<Test>::ProofTooLong.into());
+		assert_eq!(err.post_info.actual_weight, Some(0));
+		assert_eq!(ProofCount::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn expired_proof_is_reanchored_by_another_account() {
+	new_test_ext().execute_with(|| {
+		ClaimTtl::set(5);
+		let proof = nth_proof(0);
+		create(1, &proof);
+		run_to_block(6);
+		assert!(!Proofs::<Test>::contains_key(ns(), &proof));
+		assert_eq!(ExpiredProofs::<Test>::get(ns(), &proof), Some(1));
+
+		System::reset_events();
+		create(2, &proof);
+		assert_eq!(owner_of(&proof), 2);
+		assert!(poe_events().contains(&PoeEvent::ClaimReanchored(
+			2,
+			1,
+			ns(),
+			proof.clone(),
+			10,
+			6
+		)));
+		assert!(!poe_events().iter().any(|e| matches!(e, PoeEvent::ClaimCreated(..))));
+		// 过期记录只使用一次
+		assert!(!ExpiredProofs::<Test>::contains_key(ns(), &proof));
+	});
+}
+
+#[test]
+fn subaccount_matches_utility_derivative_account() {
+	new_test_ext().execute_with(|| {
+		// blake2_256(b"modlpy/utilisuba" ++ parent ++ index) 的前 8 个字节,
+		// 与 `pallet_utility::Pallet::derivative_account_id` 的结果相同
+		assert_eq!(PoeModule::subaccount_of(&1, 0), 13950085325555297514);
+		assert_eq!(PoeModule::subaccount_of(&1, 1), 8044104713257164453);
+		assert_eq!(PoeModule::subaccount_of(&2, 0), 9469559187530456503);
+	});
+}
+
+#[test]
+fn transfer_to_subaccount_moves_claim_to_derived_account() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		let sub = 8044104713257164453;
+		assert_ok!(PoeModule::transfer_to_subaccount(Origin::signed(1), 1, 1, ns(), proof.clone()));
+		assert_eq!(owner_of(&proof), sub);
+		assert!(poe_events().iter().any(|e| matches!(
+			e,
+			PoeEvent::ClaimTransferred(1, to, _, p, _, _) if *to == sub && *p == proof
+		)));
+	});
+}
+
+#[test]
+fn genesis_proofs_are_counted_in_account_stats() {
+	let proofs = vec![(ns(), nth_proof(0), 1), (ns(), nth_proof(1), 1), (ns(), nth_proof(2), 2)];
+	new_test_ext_with_proofs(proofs).execute_with(|| {
+		assert_eq!(ProofCount::<Test>::get(), 3);
+		assert_eq!(stats(1), (2, 2, 0));
+		assert_eq!(stats(2), (1, 1, 0));
+		assert_ok!(PoeModule::check_invariants());
+	});
+}
+
+#[test]
+fn create_claims_checks_length_after_normalizing() {
+	new_test_ext().execute_with(|| {
+		// 原始存证的长度合法, 去掉 "0x" 前缀后只剩 3 个字节
+		let proofs: BoundedVec<_, _> = vec![nth_proof(0), proof(b"0xabc")].try_into().unwrap();
+		assert_noop_ignore_postinfo!(
+			PoeModule::create_claims(Origin::signed(1), ns(), proofs),
+			Error::<Test>::InvalidProofLength
+		);
+	});
+}
+
+#[test]
+fn content_type_is_stored_under_the_normalized_proof() {
+	new_test_ext().execute_with(|| {
+		let content_type: BoundedVec<_, _> = b"text/plain".to_vec().try_into().unwrap();
+		assert_ok!(PoeModule::create_claim_with_content_type(
+			Origin::signed(1),
+			ns(),
+			proof(b"0x0xabcd"),
+			HashAlgo::Blake2_256,
+			content_type.clone(),
+		));
+		assert_eq!(owner_of(&proof(b"abcd")), 1);
+		assert_eq!(ContentTypes::<Test>::get(ns(), proof(b"abcd")), Some(content_type));
+	});
+}
+
+#[test]
+fn genesis_proofs_are_normalized() {
+	new_test_ext_with_proofs(vec![(ns(), proof(b"0xabcd"), 1)]).execute_with(|| {
+		assert_eq!(owner_of(&proof(b"abcd")), 1);
+		assert!(!Proofs::<Test>::contains_key(ns(), proof(b"0xabcd")));
+	});
+}
+
+#[test]
+fn slashed_deposit_is_not_reported_as_unreserved() {
+	new_test_ext().execute_with(|| {
+		let proof = nth_proof(0);
+		create(1, &proof);
+		System::reset_events();
+
+		assert_ok!(PoeModule::force_revoke_and_slash(Origin::root(), ns(), proof.clone()));
+		assert!(poe_events().contains(&PoeEvent::ClaimSlashed(1, 10)));
+		assert!(!poe_events().iter().any(|e| matches!(e, PoeEvent::DepositUnreserved(..))));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 10);
+		assert_eq!(TotalReservedDeposits::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn force_revoke_claims_only_unreserves_when_not_slashing() {
+	new_test_ext().execute_with(|| {
+		let (first, second) = (nth_proof(0), nth_proof(1));
+		create(1, &first);
+		create(1, &second);
+		System::reset_events();
+
+		let proofs: BoundedVec<_, _> = vec![first.clone()].try_into().unwrap();
+		assert_ok!(PoeModule::force_revoke_claims(Origin::root(), ns(), proofs, true));
+		assert!(poe_events().contains(&PoeEvent::ClaimSlashed(1, 10)));
+		assert!(!poe_events().iter().any(|e| matches!(e, PoeEvent::DepositUnreserved(..))));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 20);
+
+		System::reset_events();
+		let proofs: BoundedVec<_, _> = vec![second.clone()].try_into().unwrap();
+		assert_ok!(PoeModule::force_revoke_claims(Origin::root(), ns(), proofs, false));
+		assert!(poe_events().contains(&PoeEvent::DepositUnreserved(1, ns(), second, 10)));
+		assert!(!poe_events().iter().any(|e| matches!(e, PoeEvent::ClaimSlashed(..))));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(TotalReservedDeposits::<Test>::get(), 0);
+	});
+}
@@ -0,0 +1,155 @@
+//! Traits for other pallets to react to claim changes in pallet-poe
+
+use codec::Encode;
+use core::{marker::PhantomData, time::Duration};
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::{
+		schedule::{DispatchTime, Named as ScheduleNamed, LOWEST_PRIORITY},
+		Get, UnixTime,
+	},
+	BoundedVec,
+};
+use sp_std::prelude::*;
+
+/// 在存证被创建、转移或撤销时被调用的回调, 使其他 pallet 无需依赖本 pallet 的内部实现即可做出响应.
+///
+/// 回调在对应的存储修改完成之后调用.
+pub trait OnClaimEvent<AccountId> {
+	/// `who` 在命名空间 `namespace` 下创建了存证 `proof`.
+	fn on_created(who: &AccountId, namespace: &[u8], proof: &[u8]);
+	/// 存证 `proof` 的所有权从 `from` 转移给了 `to`.
+	fn on_transferred(from: &AccountId, to: &AccountId, namespace: &[u8], proof: &[u8]);
+	/// 持有者为 `who` 的存证 `proof` 被撤销.
+	fn on_revoked(who: &AccountId, namespace: &[u8], proof: &[u8]);
+}
+
+impl<AccountId> OnClaimEvent<AccountId> for () {
+	fn on_created(_who: &AccountId, _namespace: &[u8], _proof: &[u8]) {}
+	fn on_transferred(_from: &AccountId, _to: &AccountId, _namespace: &[u8], _proof: &[u8]) {}
+	fn on_revoked(_who: &AccountId, _namespace: &[u8], _proof: &[u8]) {}
+}
+
+/// 查询账户是否已登记身份, 在启用 `RequireIdentity` 时用于限制可以创建存证的账户.
+///
+/// 例如可以基于 `pallet_identity` 实现, 只有拥有经过注册机构认证的身份的账户才返回 `true`.
+pub trait IdentityProvider<AccountId> {
+	/// `who` 是否已登记身份.
+	fn has_identity(who: &AccountId) -> bool;
+}
+
+/// 不提供身份信息, 任何账户都被视为没有登记身份. 与 `RequireIdentity`
+/// 同时启用时没有账户可以创建存证.
+impl<AccountId> IdentityProvider<AccountId> for () {
+	fn has_identity(_who: &AccountId) -> bool {
+		false
+	}
+}
+
+/// 不提供时间戳的时间来源, 用于不包含 `pallet_timestamp` 的链. 使用它时存证不会记录创建时间.
+pub struct NoTimestamp;
+
+impl UnixTime for NoTimestamp {
+	fn now() -> Duration {
+		Duration::ZERO
+	}
+}
+
+/// 将提交的存证转换为规范形式, 使同一个哈希的不同编码(例如十六进制的大小写、是否带有 `0x`
+/// 前缀)对应同一个存证.
+///
+/// 所有接受存证的调用和运行时 API 在读取存储之前都会先转换存证, 存储中只保存规范形式.
+/// 对已有存证的链修改转换规则时, 需要一并迁移已有的存证.
+pub trait ProofNormalizer<S: Get<u32>> {
+	/// 返回 `proof` 的规范形式. 对规范形式再次转换时应当返回相同的结果.
+	fn normalize(proof: BoundedVec<u8, S>) -> BoundedVec<u8, S>;
+}
+
+/// 不做任何转换, 提交的存证即为规范形式.
+impl<S: Get<u32>> ProofNormalizer<S> for () {
+	fn normalize(proof: BoundedVec<u8, S>) -> BoundedVec<u8, S> {
+		proof
+	}
+}
+
+/// 在存证的过期区块调度一次 `handle_expiry` 调用, 使存证在该区块准确地过期,
+/// 而不是等待 `on_initialize`、`on_idle` 或 `clean_expired` 清理 `ExpiryQueue`.
+///
+/// 调度的调用必须以 `ForceOrigin` 接受的来源执行. 同一个存证同时最多只有一个调度任务.
+pub trait ExpiryScheduler<BlockNumber> {
+	/// 在 `when` 调度存证 `proof` 的过期, 无法调度时返回 `false`, 存证将改为加入 `ExpiryQueue`.
+	fn schedule(namespace: &[u8], proof: &[u8], when: BlockNumber) -> bool;
+	/// 取消存证 `proof` 的调度任务, 没有调度任务时不做任何操作.
+	fn cancel(namespace: &[u8], proof: &[u8]);
+}
+
+/// 不调度任何任务, 所有存证都通过 `ExpiryQueue` 过期.
+impl<BlockNumber> ExpiryScheduler<BlockNumber> for () {
+	fn schedule(_namespace: &[u8], _proof: &[u8], _when: BlockNumber) -> bool {
+		false
+	}
+	fn cancel(_namespace: &[u8], _proof: &[u8]) {}
+}
+
+/// 调度任务名称的前缀, 避免与其他 pallet 的具名任务冲突
+pub const EXPIRY_TASK_PREFIX: &[u8] = b"poe/expiry";
+
+/// 基于具名调度任务(例如 `pallet_scheduler`)的 `ExpiryScheduler`.
+///
+/// 任务名称为 SCALE 编码的 `(EXPIRY_TASK_PREFIX, namespace, proof)`, 以 `Root` 来源调用
+/// `handle_expiry`, 因此运行时的 `ForceOrigin` 需要接受 `Root`. 其中 `O` 是调度器使用的来源类型,
+/// 通常为运行时的 `OriginCaller`.
+pub struct NamedExpiryScheduler<T, S, O>(PhantomData<(T, S, O)>);
+
+impl<T, S, O> NamedExpiryScheduler<T, S, O> {
+	/// 存证的调度任务名称.
+	fn task_id(namespace: &[u8], proof: &[u8]) -> Vec<u8> {
+		(EXPIRY_TASK_PREFIX, namespace, proof).encode()
+	}
+}
+
+impl<T, S, O> ExpiryScheduler<T::BlockNumber> for NamedExpiryScheduler<T, S, O>
+where
+	T: crate::Config,
+	<T as frame_system::Config>::Call: From<crate::Call<T>>,
+	S: ScheduleNamed<T::BlockNumber, <T as frame_system::Config>::Call, O>,
+	O: From<frame_system::RawOrigin<T::AccountId>>,
+{
+	fn schedule(namespace: &[u8], proof: &[u8], when: T::BlockNumber) -> bool {
+		let (namespace_key, proof_key) = match (
+			crate::NamespaceOf::<T>::try_from(namespace.to_vec()),
+			BoundedVec::<u8, T::MaxBytesInHash>::try_from(proof.to_vec()),
+		) {
+			(Ok(namespace), Ok(proof)) => (namespace, proof),
+			_ => return false,
+		};
+		let call = crate::Call::<T>::handle_expiry { namespace: namespace_key, proof: proof_key };
+		S::schedule_named(
+			Self::task_id(namespace, proof),
+			DispatchTime::At(when),
+			None,
+			LOWEST_PRIORITY,
+			frame_system::RawOrigin::Root.into(),
+			call.into(),
+		)
+		.is_ok()
+	}
+
+	fn cancel(namespace: &[u8], proof: &[u8]) {
+		let _ = S::cancel_named(Self::task_id(namespace, proof));
+	}
+}
+
+/// 将跨链存证查询的结果发回发起查询的位置, 例如向 `to` 发送一条 XCM 消息,
+/// 其中的 `Transact` 以 `query_id` 和 `response` 调用对方链上处理查询结果的调用.
+pub trait QueryResponder<Location> {
+	/// 将 `query_id` 对应的查询结果 `response` 发送给 `to`.
+	fn respond(to: Location, query_id: u64, response: Vec<u8>) -> DispatchResult;
+}
+
+/// 不发送响应, 查询结果只通过 `ClaimQueryAnswered` 事件发出.
+impl<Location> QueryResponder<Location> for () {
+	fn respond(_to: Location, _query_id: u64, _response: Vec<u8>) -> DispatchResult {
+		Ok(())
+	}
+}
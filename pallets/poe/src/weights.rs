@@ -0,0 +1,2534 @@
+//! Weights for pallet_poe
+//!
+//! 占位权重: 以下数值是手工给出的保守估计, 并非基准测试的结果. 在生产环境中使用之前,
+//! 需要启用 `runtime-benchmarks` 特性, 在参考硬件上运行 `benchmark` 命令重新生成本文件.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim(l: u32, ) -> Weight;
+	fn create_claims(p: u32, ) -> Weight;
+	fn transfer_claim() -> Weight;
+	fn revoke_claim() -> Weight;
+	fn set_metadata() -> Weight;
+	fn renew_claim() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn transfer_from() -> Weight;
+	fn offer_transfer() -> Weight;
+	fn accept_transfer() -> Weight;
+	fn cancel_transfer() -> Weight;
+	fn create_soulbound_claim() -> Weight;
+	fn force_revoke_claim() -> Weight;
+	fn set_paused() -> Weight;
+	fn update_claim() -> Weight;
+	fn add_creator() -> Weight;
+	fn remove_creator() -> Weight;
+	fn lock_claim() -> Weight;
+	fn unlock_claim() -> Weight;
+	fn create_claim_signed() -> Weight;
+	fn burn_claim() -> Weight;
+	fn add_revoke_delegate() -> Weight;
+	fn remove_revoke_delegate() -> Weight;
+	fn set_tags(t: u32, ) -> Weight;
+	fn force_revoke_and_slash() -> Weight;
+	fn create_shared_claim(o: u32, ) -> Weight;
+	fn approve_revoke() -> Weight;
+	fn council_revoke_claim() -> Weight;
+	fn challenge_claim() -> Weight;
+	fn resolve_challenge() -> Weight;
+	fn transfer_claim_with_memo(m: u32, ) -> Weight;
+	fn create_superseding_claim() -> Weight;
+	fn transfer_claims(p: u32, ) -> Weight;
+	fn list_for_sale() -> Weight;
+	fn buy_claim() -> Weight;
+	fn delist() -> Weight;
+	fn freeze_account() -> Weight;
+	fn unfreeze_account() -> Weight;
+	fn set_content_type() -> Weight;
+	fn add_metadata_editor() -> Weight;
+	fn remove_metadata_editor() -> Weight;
+	fn clean_expired(n: u32, ) -> Weight;
+	fn allow_recipient() -> Weight;
+	fn disallow_recipient() -> Weight;
+	fn create_claim_with_priority(l: u32, ) -> Weight;
+	fn propose_claim(a: u32, ) -> Weight;
+	fn approve_pending_claim() -> Weight;
+	fn remove_pending_claim() -> Weight;
+	fn renew_claims(p: u32, ) -> Weight;
+	fn create_claim_no_deposit(l: u32, ) -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn safe_transfer_claim() -> Weight;
+	fn pin_claim() -> Weight;
+	fn unpin_claim() -> Weight;
+	fn lock_my_transfers() -> Weight;
+	fn unlock_my_transfers() -> Weight;
+	fn namespace_force_revoke() -> Weight;
+	fn create_namespace() -> Weight;
+	fn transfer_to_subaccount() -> Weight;
+	fn rebuild_bloom(n: u32, ) -> Weight;
+	fn force_revoke_claims(p: u32, ) -> Weight;
+	fn set_private() -> Weight;
+	fn grant_view() -> Weight;
+	fn revoke_view() -> Weight;
+	fn transfer_and_set_metadata() -> Weight;
+	fn set_namespace_max_proofs() -> Weight;
+	fn on_initialize(e: u32, w: u32, c: u32, ) -> Weight;
+	fn handle_expiry() -> Weight;
+	fn query_claim() -> Weight;
+}
+
+/// Weights for pallet_poe using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claim(l: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((40_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(23 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claims(p: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 10_000
+			.saturating_add((27_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_claim() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:1 w:0)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule TombstoneDeposits (r:0 w:1)
+	fn revoke_claim() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(39 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule MetadataVersion (r:1 w:1)
+	// Storage: PoeModule MetadataEditors (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_metadata() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:2)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn renew_claim() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn approve_transfer() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:3 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_from() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn offer_transfer() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn accept_transfer() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn cancel_transfer() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule Soulbound (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_soulbound_claim() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(23 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn force_revoke_claim() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(37 as Weight))
+	}
+	// Storage: PoeModule Paused (r:0 w:1)
+	fn set_paused() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:2)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:1 w:2)
+	// Storage: PoeModule ProofTimestamps (r:1 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule Soulbound (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:2)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofTags (r:1 w:2)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:2 w:4)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:2)
+	// Storage: PoeModule ContentTypes (r:1 w:2)
+	// Storage: PoeModule MetadataEditors (r:1 w:2)
+	// Storage: PoeModule TransferAllowlist (r:1 w:2)
+	// Storage: PoeModule TransferBonds (r:1 w:2)
+	// Storage: PoeModule ProofPriority (r:2 w:2)
+	// Storage: PoeModule PriorityIndex (r:0 w:2)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:1 w:1)
+	// Storage: PoeModule ViewPermissions (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	fn update_claim() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(32 as Weight))
+			.saturating_add(T::DbWeight::get().writes(51 as Weight))
+	}
+	// Storage: PoeModule AllowedCreators (r:0 w:1)
+	fn add_creator() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule AllowedCreators (r:0 w:1)
+	fn remove_creator() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	fn lock_claim() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	fn unlock_claim() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule AccountNonces (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claim_signed() -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().writes(23 as Weight))
+	}
+	// Storage: PoeModule RevokedProofs (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule TombstoneDeposits (r:1 w:1)
+	fn burn_claim() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(23 as Weight))
+			.saturating_add(T::DbWeight::get().writes(38 as Weight))
+	}
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn add_revoke_delegate() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn remove_revoke_delegate() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule TagIndex (r:0 w:2)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_tags(t: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((6_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn force_revoke_and_slash() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes(38 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_shared_claim(o: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((4_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule RevokeApprovals (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn approve_revoke() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn council_revoke_claim() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(36 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn challenge_claim() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn resolve_challenge() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes(37 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_claim_with_memo(m: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:1)
+	// Storage: PoeModule SupersededBy (r:1 w:1)
+	// Storage: PoeModule Supersedes (r:0 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_superseding_claim() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(24 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:3 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_claims(p: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((36_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Soulbound (r:1 w:0)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn list_for_sale() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Soulbound (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn buy_claim() -> Weight {
+		(62_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn delist() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule FrozenAccounts (r:0 w:1)
+	fn freeze_account() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule FrozenAccounts (r:0 w:1)
+	fn unfreeze_account() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_content_type() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule MetadataEditors (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn add_metadata_editor() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule MetadataEditors (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn remove_metadata_editor() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule ExpiryCursor (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ExpiredProofs (r:0 w:3)
+	// Storage: PoeModule ExpiredRecordRange (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordQueue (r:2 w:3)
+	// Storage: PoeModule ExpiredRecordIndex (r:2 w:3)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn clean_expired(n: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((25_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((15 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((24 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule TransferAllowlist (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn allow_recipient() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule TransferAllowlist (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn disallow_recipient() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:271 w:2)
+	// Storage: PoeModule LockedProofs (r:16 w:0)
+	// Storage: PoeModule Challenges (r:16 w:0)
+	// Storage: PoeModule SharedOwners (r:16 w:0)
+	// Storage: PoeModule ProofPriority (r:2 w:2)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:17 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:2 w:2)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn create_claim_with_priority(l: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((40_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(381 as Weight))
+			.saturating_add(T::DbWeight::get().writes(52 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule PendingClaimDeposits (r:0 w:1)
+	fn propose_claim(a: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:2 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:0 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule PendingClaimDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn approve_pending_claim() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(24 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule PendingClaimDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_pending_claim() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:2)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn renew_claims(p: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((28_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claim_no_deposit(l: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((40_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Attributes (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_attribute() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Attributes (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn clear_attribute() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn safe_transfer_claim() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	fn pin_claim() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	fn unpin_claim() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule AccountTransferLock (r:1 w:1)
+	fn lock_my_transfers() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule AccountTransferLock (r:1 w:1)
+	fn unlock_my_transfers() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn namespace_force_revoke() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(37 as Weight))
+	}
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	fn create_namespace() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_to_subaccount() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofBloom (r:0 w:1)
+	fn rebuild_bloom(n: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((4_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn force_revoke_claims(p: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((41_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads((17 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((37 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_private() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn grant_view() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn revoke_view() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule MetadataVersion (r:1 w:1)
+	fn transfer_and_set_metadata() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	fn set_namespace_max_proofs() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule CreationsThisBlock (r:0 w:1)
+	// Storage: PoeModule NewClaims (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryWarned (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:0)
+	fn on_initialize(e: u32, w: u32, c: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((30_000_000 as Weight).saturating_mul(e as Weight))
+			// Standard Error: 1_000
+			.saturating_add((12_000_000 as Weight).saturating_mul(w as Weight))
+			// Standard Error: 1_000
+			.saturating_add((2_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((17 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((26 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ExpiredProofs (r:0 w:1)
+	// Storage: PoeModule ExpiredRecordQueue (r:1 w:2)
+	// Storage: PoeModule ExpiredRecordIndex (r:1 w:2)
+	// Storage: PoeModule ExpiredRecordRange (r:1 w:1)
+	fn handle_expiry() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(26 as Weight))
+	}
+	// Storage: PoeModule PrivateProofs (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	fn query_claim() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claim(l: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((40_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(23 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claims(p: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 10_000
+			.saturating_add((27_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((7 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_claim() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:1 w:0)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule TombstoneDeposits (r:0 w:1)
+	fn revoke_claim() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(39 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule MetadataVersion (r:1 w:1)
+	// Storage: PoeModule MetadataEditors (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_metadata() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:2)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn renew_claim() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn approve_transfer() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:3 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_from() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn offer_transfer() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn accept_transfer() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn cancel_transfer() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule Soulbound (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_soulbound_claim() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(23 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn force_revoke_claim() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(37 as Weight))
+	}
+	// Storage: PoeModule Paused (r:0 w:1)
+	fn set_paused() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:2)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:1 w:2)
+	// Storage: PoeModule ProofTimestamps (r:1 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule Soulbound (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:2)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofTags (r:1 w:2)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:2 w:4)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:2)
+	// Storage: PoeModule ContentTypes (r:1 w:2)
+	// Storage: PoeModule MetadataEditors (r:1 w:2)
+	// Storage: PoeModule TransferAllowlist (r:1 w:2)
+	// Storage: PoeModule TransferBonds (r:1 w:2)
+	// Storage: PoeModule ProofPriority (r:2 w:2)
+	// Storage: PoeModule PriorityIndex (r:0 w:2)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:1 w:1)
+	// Storage: PoeModule ViewPermissions (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	fn update_claim() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(32 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(51 as Weight))
+	}
+	// Storage: PoeModule AllowedCreators (r:0 w:1)
+	fn add_creator() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule AllowedCreators (r:0 w:1)
+	fn remove_creator() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	fn lock_claim() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	fn unlock_claim() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule AccountNonces (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claim_signed() -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(23 as Weight))
+	}
+	// Storage: PoeModule RevokedProofs (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule TombstoneDeposits (r:1 w:1)
+	fn burn_claim() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(23 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(38 as Weight))
+	}
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn add_revoke_delegate() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn remove_revoke_delegate() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule TagIndex (r:0 w:2)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_tags(t: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((6_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn force_revoke_and_slash() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(19 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(38 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_shared_claim(o: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((4_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule RevokeApprovals (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn approve_revoke() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn council_revoke_claim() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(36 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn challenge_claim() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn resolve_challenge() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(19 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(37 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_claim_with_memo(m: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:1)
+	// Storage: PoeModule SupersededBy (r:1 w:1)
+	// Storage: PoeModule Supersedes (r:0 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_superseding_claim() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(24 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:3 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_claims(p: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((36_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((7 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Soulbound (r:1 w:0)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn list_for_sale() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Soulbound (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn buy_claim() -> Weight {
+		(62_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn delist() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule FrozenAccounts (r:0 w:1)
+	fn freeze_account() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule FrozenAccounts (r:0 w:1)
+	fn unfreeze_account() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_content_type() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule MetadataEditors (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn add_metadata_editor() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule MetadataEditors (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn remove_metadata_editor() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule ExpiryCursor (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ExpiredProofs (r:0 w:3)
+	// Storage: PoeModule ExpiredRecordRange (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordQueue (r:2 w:3)
+	// Storage: PoeModule ExpiredRecordIndex (r:2 w:3)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn clean_expired(n: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((25_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((15 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((24 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule TransferAllowlist (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn allow_recipient() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule TransferAllowlist (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn disallow_recipient() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:271 w:2)
+	// Storage: PoeModule LockedProofs (r:16 w:0)
+	// Storage: PoeModule Challenges (r:16 w:0)
+	// Storage: PoeModule SharedOwners (r:16 w:0)
+	// Storage: PoeModule ProofPriority (r:2 w:2)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:17 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:2 w:2)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn create_claim_with_priority(l: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((40_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(381 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(52 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule PendingClaimDeposits (r:0 w:1)
+	fn propose_claim(a: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:2 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:0 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule PendingClaimDeposits (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn approve_pending_claim() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(24 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule PendingClaimDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_pending_claim() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:2)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn renew_claims(p: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((28_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule CreationsThisBlock (r:1 w:1)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	// Storage: PoeModule NewClaims (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	// Storage: PoeModule ExpiredProofs (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule RecentProofs (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule ProofBloom (r:1 w:1)
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule ExpiredRecordIndex (r:0 w:1)
+	fn create_claim_no_deposit(l: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((40_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Attributes (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_attribute() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Attributes (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn clear_attribute() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn safe_transfer_claim() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	fn pin_claim() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	fn unpin_claim() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule AccountTransferLock (r:1 w:1)
+	fn lock_my_transfers() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule AccountTransferLock (r:1 w:1)
+	fn unlock_my_transfers() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule Approvals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule ProofTimestamps (r:0 w:1)
+	// Storage: PoeModule TransferHistory (r:0 w:1)
+	// Storage: PoeModule LockedProofs (r:0 w:1)
+	// Storage: PoeModule ProofTags (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn namespace_force_revoke() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(37 as Weight))
+	}
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule RevokedProofs (r:1 w:0)
+	fn create_namespace() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	fn transfer_to_subaccount() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: PoeModule BloomRebuild (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ProofBloom (r:0 w:1)
+	fn rebuild_bloom(n: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((4_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule LastTransferAt (r:0 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:1)
+	// Storage: PoeModule ProofHashAlgo (r:0 w:1)
+	// Storage: PoeModule Challenges (r:1 w:1)
+	// Storage: PoeModule MetadataVersion (r:0 w:1)
+	// Storage: PoeModule Supersedes (r:1 w:1)
+	// Storage: PoeModule Listings (r:0 w:1)
+	// Storage: PoeModule ExpiryWarned (r:0 w:1)
+	// Storage: PoeModule ProofIds (r:1 w:1)
+	// Storage: PoeModule ContentTypes (r:0 w:1)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:0 w:1)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule ProofPriority (r:1 w:1)
+	// Storage: PoeModule PriorityIndex (r:0 w:1)
+	// Storage: PoeModule AccountStats (r:1 w:1)
+	// Storage: PoeModule AttributeCount (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:1)
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule TotalReservedDeposits (r:1 w:1)
+	fn force_revoke_claims(p: u32, ) -> Weight {
+		(10_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((41_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads((17 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((37 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PrivateProofs (r:0 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn set_private() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn grant_view() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:1 w:0)
+	fn revoke_view() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	// Storage: PoeModule Approvals (r:1 w:1)
+	// Storage: PoeModule TransferHistory (r:1 w:1)
+	// Storage: PoeModule LockedProofs (r:1 w:0)
+	// Storage: PoeModule LastTransferAt (r:1 w:1)
+	// Storage: PoeModule SharedOwners (r:1 w:0)
+	// Storage: PoeModule Listings (r:1 w:1)
+	// Storage: PoeModule FrozenAccounts (r:2 w:0)
+	// Storage: PoeModule MetadataEditors (r:0 w:1)
+	// Storage: PoeModule TransferAllowlist (r:1 w:0)
+	// Storage: PoeModule TransferBonds (r:1 w:1)
+	// Storage: PoeModule AccountStats (r:2 w:2)
+	// Storage: PoeModule AccountTransferLock (r:1 w:0)
+	// Storage: PoeModule ViewPermissions (r:0 w:1)
+	// Storage: PoeModule ProofMetadata (r:0 w:1)
+	// Storage: PoeModule MetadataVersion (r:1 w:1)
+	fn transfer_and_set_metadata() -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
+	// Storage: PoeModule NamespaceConfig (r:1 w:1)
+	fn set_namespace_max_proofs() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule CreationsThisBlock (r:0 w:1)
+	// Storage: PoeModule NewClaims (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiryWarned (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:0)
+	fn on_initialize(e: u32, w: u32, c: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((30_000_000 as Weight).saturating_mul(e as Weight))
+			// Standard Error: 1_000
+			.saturating_add((12_000_000 as Weight).saturating_mul(w as Weight))
+			// Standard Error: 1_000
+			.saturating_add((2_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((17 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((26 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: PoeModule ProofExpiry (r:1 w:1)
+	// Storage: PoeModule Pinned (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ExpiredProofs (r:0 w:1)
+	// Storage: PoeModule ExpiredRecordQueue (r:1 w:2)
+	// Storage: PoeModule ExpiredRecordIndex (r:1 w:2)
+	// Storage: PoeModule ExpiredRecordRange (r:1 w:1)
+	fn handle_expiry() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(26 as Weight))
+	}
+	// Storage: PoeModule PrivateProofs (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	fn query_claim() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+	}
+}
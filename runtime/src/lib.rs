@@ -98,12 +98,12 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -264,10 +264,78 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+parameter_types! {
+	/// 创建一个存证需要保留的押金
+	pub const ClaimDeposit: Balance = 10_000;
+	pub const ChallengeBond: Balance = 10_000;
+	pub const TransferBond: Balance = 1_000;
+	pub const RenewalFee: Balance = 1_000;
+	pub const PriorityDeposit: Balance = 1_000;
+}
+
 /// 配置 pallet-template 于 pallets/template 之中.
 impl pallet_poe::Config for Runtime {
 	type Event = Event;
+	type Currency = Balances;
+	type ClaimDeposit = ClaimDeposit;
+	type MinBytesInHash = frame_support::traits::ConstU32<16>;
 	type MaxBytesInHash = frame_support::traits::ConstU32<64>;
+	type MaxNamespaceLen = frame_support::traits::ConstU32<32>;
+	type MaxMetadataLength = frame_support::traits::ConstU32<256>;
+	type MaxMemoLen = frame_support::traits::ConstU32<128>;
+	type MaxMimeLen = frame_support::traits::ConstU32<64>;
+	type MaxKeyLen = frame_support::traits::ConstU32<32>;
+	type MaxValueLen = frame_support::traits::ConstU32<256>;
+	type MaxAttributesPerProof = frame_support::traits::ConstU32<16>;
+	type MaxTagLen = frame_support::traits::ConstU32<32>;
+	type MaxTagsPerProof = frame_support::traits::ConstU32<8>;
+	type MaxBatchSize = frame_support::traits::ConstU32<100>;
+	type ClaimTtl = frame_support::traits::ConstU32<{ 365 * DAYS }>;
+	type MaxExpiredPerBlock = frame_support::traits::ConstU32<100>;
+	type MaxExpiredRecords = frame_support::traits::ConstU32<100_000>;
+	type AutoExpire = frame_support::traits::ConstBool<false>;
+	type IdleExpire = frame_support::traits::ConstBool<true>;
+	type ExpiryScheduler = ();
+	type ExpiryWarningBlocks = frame_support::traits::ConstU32<{ 7 * DAYS }>;
+	type MaxExpiryWarningsPerBlock = frame_support::traits::ConstU32<100>;
+	type MaxProofs = frame_support::traits::ConstU32<1_000_000>;
+	type ClaimEviction = frame_support::traits::ConstBool<false>;
+	type PriorityDeposit = PriorityDeposit;
+	type MaxClaimsPerAccount = frame_support::traits::ConstU32<1000>;
+	type MaxApprovers = frame_support::traits::ConstU32<16>;
+	type PendingClaimTtl = frame_support::traits::ConstU32<{ 7 * DAYS }>;
+	type MaxCreationsPerBlock = frame_support::traits::ConstU32<100>;
+	type MaxHistoryLength = frame_support::traits::ConstU32<100>;
+	type TransferCooldown = frame_support::traits::ConstU32<{ 10 * MINUTES }>;
+	type MaxOwners = frame_support::traits::ConstU32<8>;
+	type MaxMetadataEditors = frame_support::traits::ConstU32<4>;
+	type MaxViewers = frame_support::traits::ConstU32<16>;
+	type MaxAllowedRecipients = frame_support::traits::ConstU32<16>;
+	type ClaimNotifications = frame_support::traits::ConstBool<false>;
+	type RecentBufferLen = frame_support::traits::ConstU32<100>;
+	type BloomBytes = frame_support::traits::ConstU32<4096>;
+	type ChallengeBond = ChallengeBond;
+	type TransferBond = TransferBond;
+	type RenewalFee = RenewalFee;
+	type ChallengePeriod = frame_support::traits::ConstU32<{ 7 * DAYS }>;
+	type RoyaltyBps = frame_support::traits::ConstU32<250>;
+	type PermissionedCreation = frame_support::traits::ConstBool<false>;
+	type RequireIdentity = frame_support::traits::ConstBool<false>;
+	type IdentityProvider = ();
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type CouncilOrigin = frame_system::EnsureRoot<AccountId>;
+	type DepositWaiverOrigin = frame_system::EnsureRoot<AccountId>;
+	type UnixTime = Timestamp;
+	type OnClaimHandler = ();
+	type OnSlash = ();
+	type OnRenewalFee = ();
+	type AuthoritySignature = Signature;
+	type AuthoritySigner = <Signature as Verify>::Signer;
+	type ProofNormalizer = ();
+	type QueryOrigin = frame_system::EnsureRoot<AccountId>;
+	type QueryLocation = ();
+	type QueryResponder = ();
+	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -306,6 +374,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_poe::extensions::CheckProofSize<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -331,6 +400,7 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
+		[pallet_poe, PoeModule]
 	);
 }
 
@@ -450,6 +520,190 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, pallet_poe::HashAlgo>
+		for Runtime
+	{
+		fn get_proof_info(namespace: Vec<u8>, proof: Vec<u8>) -> Option<(AccountId, BlockNumber)> {
+			PoeModule::proof_info(namespace, proof)
+		}
+
+		fn get_proof_info_signed(
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+			viewer: AccountId,
+			signature: Vec<u8>,
+		) -> Option<(AccountId, BlockNumber)> {
+			PoeModule::proof_info_signed(namespace, proof, viewer, signature)
+		}
+
+		fn proofs_of(account: AccountId) -> Vec<(Vec<u8>, Vec<u8>)> {
+			PoeModule::claims_of(&account)
+				.into_iter()
+				.map(|(namespace, proof)| (namespace.into_inner(), proof.into_inner()))
+				.collect()
+		}
+
+		fn proofs_in_namespace(namespace: Vec<u8>) -> Vec<Vec<u8>> {
+			PoeModule::proofs_in_namespace(namespace)
+				.into_iter()
+				.map(|proof| proof.into_inner())
+				.collect()
+		}
+
+		fn verify_claim(namespace: Vec<u8>, proof: Vec<u8>) -> Option<(AccountId, BlockNumber)> {
+			PoeModule::proof_info(namespace, proof)
+		}
+
+		fn proofs_by_tag(namespace: Vec<u8>, tag: Vec<u8>) -> Vec<Vec<u8>> {
+			PoeModule::proofs_by_tag(namespace, tag).into_iter().map(|proof| proof.into_inner()).collect()
+		}
+
+		fn proofs_exist(namespace: Vec<u8>, proofs: Vec<Vec<u8>>) -> Vec<bool> {
+			PoeModule::proofs_exist(namespace, proofs)
+		}
+
+		fn claim_status(namespace: Vec<u8>, proof: Vec<u8>) -> pallet_poe_runtime_api::ClaimStatus {
+			use pallet_poe_runtime_api::ClaimStatus;
+
+			if PoeModule::proof_info(namespace.clone(), proof.clone()).is_some() {
+				ClaimStatus::Active
+			} else if PoeModule::revocation_info(namespace, proof).is_some() {
+				ClaimStatus::Revoked
+			} else {
+				ClaimStatus::Unknown
+			}
+		}
+
+		fn total_proofs() -> u32 {
+			PoeModule::total_proofs()
+		}
+
+		fn remaining_capacity() -> u32 {
+			PoeModule::remaining_capacity()
+		}
+
+		fn list_proofs(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, Vec<u8>, AccountId, BlockNumber)>, Option<Vec<u8>>) {
+			let (page, next_key) = PoeModule::list_proofs(start_key, limit);
+			let page = page
+				.into_iter()
+				.map(|(namespace, proof, owner, block_number)| {
+					(namespace.into_inner(), proof.into_inner(), owner, block_number)
+				})
+				.collect();
+			(page, next_key)
+		}
+
+		fn hash_algo(namespace: Vec<u8>, proof: Vec<u8>) -> Option<pallet_poe::HashAlgo> {
+			PoeModule::hash_algo(namespace, proof)
+		}
+
+		fn proof_by_id(id: u64) -> Option<(Vec<u8>, Vec<u8>)> {
+			PoeModule::proof_by_id(id)
+				.map(|(namespace, proof)| (namespace.into_inner(), proof.into_inner()))
+		}
+
+		fn claim_id(namespace: Vec<u8>, proof: Vec<u8>) -> Option<u64> {
+			PoeModule::claim_id(namespace, proof)
+		}
+
+		fn superseded_by(namespace: Vec<u8>, proof: Vec<u8>) -> Option<Vec<u8>> {
+			PoeModule::superseded_by(namespace, proof).map(|proof| proof.into_inner())
+		}
+
+		fn supersession_chain(namespace: Vec<u8>, proof: Vec<u8>) -> Vec<Vec<u8>> {
+			PoeModule::supersession_chain(namespace, proof)
+				.into_iter()
+				.map(|proof| proof.into_inner())
+				.collect()
+		}
+
+		fn proof_timestamp(namespace: Vec<u8>, proof: Vec<u8>) -> Option<u64> {
+			PoeModule::proof_timestamp(namespace, proof)
+		}
+
+		fn transfer_history(namespace: Vec<u8>, proof: Vec<u8>) -> Vec<(AccountId, BlockNumber)> {
+			PoeModule::transfer_history(namespace, proof)
+		}
+
+		fn content_type(namespace: Vec<u8>, proof: Vec<u8>) -> Option<Vec<u8>> {
+			PoeModule::content_type(namespace, proof).map(|content_type| content_type.into_inner())
+		}
+
+		fn generate_claim_key(namespace: Vec<u8>, proof: Vec<u8>) -> Option<Vec<u8>> {
+			PoeModule::claim_key(namespace, proof)
+		}
+
+		fn can_create(who: AccountId, namespace: Vec<u8>, proof: Vec<u8>) -> Result<(), u8> {
+			PoeModule::can_create(&who, namespace, proof)
+		}
+
+		fn verify_inclusion(
+			namespace: Vec<u8>,
+			root: Vec<u8>,
+			leaf: Vec<u8>,
+			path: Vec<(Vec<u8>, bool)>,
+		) -> bool {
+			PoeModule::verify_inclusion(namespace, root, leaf, path)
+		}
+
+		fn attributes_of(namespace: Vec<u8>, proof: Vec<u8>) -> Vec<(Vec<u8>, Vec<u8>)> {
+			PoeModule::attributes_of(namespace, proof)
+				.into_iter()
+				.map(|(key, value)| (key.into_inner(), value.into_inner()))
+				.collect()
+		}
+
+		fn recent_proofs() -> Vec<(Vec<u8>, Vec<u8>, AccountId, BlockNumber)> {
+			PoeModule::recent_proofs()
+				.into_iter()
+				.map(|(namespace, proof, owner, block)| {
+					(namespace.into_inner(), proof.into_inner(), owner, block)
+				})
+				.collect()
+		}
+
+		fn can_transfer(
+			from: AccountId,
+			to: AccountId,
+			namespace: Vec<u8>,
+			proof: Vec<u8>,
+		) -> Result<(), u8> {
+			PoeModule::can_transfer(&from, &to, namespace, proof)
+		}
+
+		fn bloom_might_contain(namespace: Vec<u8>, proof: Vec<u8>) -> bool {
+			PoeModule::bloom_might_contain(namespace, proof)
+		}
+
+		fn claim_nonce(account: AccountId) -> u64 {
+			PoeModule::account_nonce(&account)
+		}
+
+		fn revocation_info(namespace: Vec<u8>, proof: Vec<u8>) -> Option<(AccountId, BlockNumber)> {
+			PoeModule::revocation_info(namespace, proof)
+		}
+
+		fn stats_of(account: AccountId) -> (u32, u32, u32) {
+			let stats = PoeModule::stats_of(&account);
+			(stats.active, stats.created, stats.revoked)
+		}
+
+		fn proofs_in_range(
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Vec<(Vec<u8>, Vec<u8>, AccountId, BlockNumber)> {
+			PoeModule::proofs_in_range(from, to)
+				.into_iter()
+				.map(|(namespace, proof, owner, block_number)| {
+					(namespace.into_inner(), proof.into_inner(), owner, block_number)
+				})
+				.collect()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,